@@ -1,7 +1,7 @@
 extern crate clige;
 use clige::core::{
     buffer::{Buffer, PixelBuffer},
-    color::{Color, Context},
+    color::Color,
     data::Pixel,
 };
 use std::{collections::HashMap, fmt::Debug};
@@ -80,15 +80,17 @@ fn main() {
         match i {
             'F' => {
                 translate(&mut position, (city.width() as i32, city.height() as i32));
-                city.set(position.0 as usize, position.1 as usize, '•'.into());
+                city
+                    .set(position.0 as usize, position.1 as usize, '•'.into())
+                    .unwrap();
                 println!("Move: {:?}", position)
             }
             '[' => {
                 println!("Mark Location: {:?}", position);
-                marks.push(position.clone())
+                marks.push(position)
             }
             ']' => {
-                if marks.len() == 0 {
+                if marks.is_empty() {
                     panic!("No marks found")
                 }
                 println!("Jump to Mark: {:?}", marks.last().unwrap());
@@ -115,6 +117,7 @@ fn main() {
     println!("{}", city.render().unwrap());
 }
 
+#[allow(dead_code)]
 fn get_road(dir: i8) -> Pixel {
     match dir {
         0 => {
@@ -141,11 +144,11 @@ fn translate(pos: &mut (i32, i32, i8), max: (i32, i32)) {
         }
         1 => {
             pos.0 += 1;
-            pos.0 = pos.0.min(max.0 as i32 - 1);
+            pos.0 = pos.0.min(max.0 - 1);
         }
         2 => {
             pos.1 += 1;
-            pos.1 = pos.1.min(max.1 as i32 - 1);
+            pos.1 = pos.1.min(max.1 - 1);
         }
         3 => {
             pos.0 -= 1;
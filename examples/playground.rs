@@ -7,9 +7,9 @@ use std::{io, thread::sleep};
 use clige::core::{
     buffer::{Buffer, PixelBuffer},
     color::{Color, Context},
-    data::Pixel,
-    noise_map::NoiseMap,
+    data::{Pixel, Rect},
 };
+use clige::gen::NoiseMap;
 use rand::{thread_rng, Rng};
 
 // fn update(dt: f32) -> Result<(), String> {
@@ -33,44 +33,37 @@ fn main() {
     let mut buffer = PixelBuffer::default();
 
     let scale = 100;
-    let noise = NoiseMap::fbm(thread_rng().gen::<u32>())
-        .size(500, 500)
-        .scale(10.)
+    let noise = NoiseMap::fbm(thread_rng().gen::<u32>() as i64)
+        .step(0.1, 0.1)
         .bounds(-1., 1.)
         .build();
 
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+
     print!("\x1b[?25h");
 
     let frame_rate: f32 = 1. / 12.; // 12 frames per second
     for i in 0..scale {
         let start = Instant::now();
         print!("\x1b[H");
-        for h in 0..buffer.height() {
-            for w in 0..buffer.width() {
-                let sample = noise.get(i + w, i + h);
-                let mut n = (11. * sample) as i16;
+        let area = Rect::from([i, i, i + buffer.width(), i + buffer.height()]);
+        noise.fill_buffer_parallel(&mut buffer, area, threads, |sample| {
+            let mut n = (11. * sample) as i16;
 
-                if n < -11 || n > 11 {
-                    panic!("Invalid noise value: {} = {}", sample, n);
-                }
-                if n < 0 {
-                    n = 232 + (n.abs() * 1);
-                } else {
-                    n = 232 + 11 + (n * 1);
-                }
+            if !(-11..=11).contains(&n) {
+                panic!("Invalid noise value: {} = {}", sample, n);
+            }
+            if n < 0 {
+                n = 232 + n.abs();
+            } else {
+                n += 232 + 11;
+            }
 
-                buffer
-                    .set(
-                        w,
-                        h,
-                        Pixel {
-                            value: '█',
-                            color: Color::xterm(n as u8, Context::Foreground),
-                        },
-                    )
-                    .unwrap();
+            Pixel {
+                value: '█',
+                color: Color::xterm(n as u8, Context::Foreground),
             }
-        }
+        });
         print!("{}", buffer.render().unwrap());
         io::stdout().flush().unwrap();
 
@@ -78,9 +71,9 @@ fn main() {
         if dt > 0. {
             sleep(Duration::from_millis(dt as u64));
             // update(frame_rate).unwrap();
-        } else if dt > 0. {
+        } else {
+            // Running behind, so carry straight on to the next frame
             // update(dt).unwrap();
-            panic!("Took too long");
         }
     }
 
@@ -16,9 +16,13 @@
 //!
 extern crate clige;
 use clige::gen::{NoiseMap, Random};
+use clige::grid::{path::Pathfinder, Grid};
 use rand::Rng;
 //sige
 
+const WIDTH: usize = 60;
+const HEIGHT: usize = 20;
+
 fn main() {
     // let mut rng = Random::new();
 
@@ -31,9 +35,26 @@ fn main() {
         .build();
 
     println!("{}", rng.seed());
-    println!(
-        "{} {}",
-        rng.generator().gen_range(0..100),
-        noise.get(600, 100_052)
-    )
+
+    // Roads follow the terrain, avoiding steep slopes and water
+    let height = Grid::from_fn(WIDTH, HEIGHT, |x, y| noise.get(x, y));
+    let start = (0, rng.generator().gen_range(0..HEIGHT));
+    let goal = (WIDTH - 1, rng.generator().gen_range(0..HEIGHT));
+    let road = Pathfinder::new(&height, |from: &f64, to: &f64| {
+        (*to > -0.5).then_some(1. + (to - from).abs() * 10.)
+    })
+    .astar(start, goal);
+
+    let mut map = height.map(|h| if *h > -0.5 { '.' } else { '~' });
+    match road {
+        Some(road) => {
+            for cell in road.cells {
+                map[cell] = '#';
+            }
+        }
+        None => println!("No route from {:?} to {:?}", start, goal),
+    }
+    for y in 0..HEIGHT {
+        println!("{}", (0..WIDTH).map(|x| map[(x, y)]).collect::<String>());
+    }
 }
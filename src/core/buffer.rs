@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 use super::{
     color::Color,
-    data::{Pixel, Rect},
+    data::Pixel,
     get_term_size,
 };
 
@@ -54,13 +56,44 @@ pub trait Buffer {
 /// A pixel buffer.
 ///
 /// Each index in the buffer stores `char` and `Color` data.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "PixelBufferData")]
 pub struct PixelBuffer {
     pub pixels: Vec<Pixel>,
     width: usize,
     height: usize,
 }
 
+/// Serialized form of a [`PixelBuffer`], checked before it is turned back into one
+#[derive(Deserialize)]
+struct PixelBufferData {
+    pixels: Vec<Pixel>,
+    width: usize,
+    height: usize,
+}
+
+impl TryFrom<PixelBufferData> for PixelBuffer {
+    type Error = String;
+
+    fn try_from(data: PixelBufferData) -> Result<Self, Self::Error> {
+        if data.pixels.len() != data.width * data.height {
+            return Err(format!(
+                "Expected {} pixels for a {}x{} buffer, found {}",
+                data.width * data.height,
+                data.width,
+                data.height,
+                data.pixels.len()
+            ));
+        }
+
+        Ok(PixelBuffer {
+            pixels: data.pixels,
+            width: data.width,
+            height: data.height,
+        })
+    }
+}
+
 impl Default for PixelBuffer {
     /// Pixel buffer that is the width and height of the terminal
     fn default() -> Self {
@@ -129,7 +162,9 @@ impl Buffer for PixelBuffer {
         let mut color = Color::default();
 
         for (i, pixel) in self.pixels.iter().enumerate() {
-            if pixel.color != color {
+            if pixel.value == Pixel::CONTINUATION {
+                // Covered by the wide character in the previous cell
+            } else if pixel.color != color {
                 color = pixel.color.clone();
                 output.push_str(format!("{}{}", pixel.color, pixel.value).as_str());
             } else {
@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+use serde::{Deserialize, Serialize};
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
 pub enum Context {
     #[default]
@@ -8,7 +10,7 @@ pub enum Context {
     Solid,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Default)]
 pub enum SystemColor {
     Black,
     Red,
@@ -22,6 +24,30 @@ pub enum SystemColor {
 }
 
 impl SystemColor {
+    const ALL: [SystemColor; 8] = [
+        SystemColor::Black,
+        SystemColor::Red,
+        SystemColor::Green,
+        SystemColor::Yellow,
+        SystemColor::Blue,
+        SystemColor::Magenta,
+        SystemColor::Cyan,
+        SystemColor::White,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Black => "black",
+            Self::Red => "red",
+            Self::Green => "green",
+            Self::Yellow => "yellow",
+            Self::Blue => "blue",
+            Self::Magenta => "magenta",
+            Self::Cyan => "cyan",
+            Self::White => "white",
+        }
+    }
+
     pub fn color(&self) -> u8 {
         match self {
             Self::Black => 0,
@@ -36,7 +62,7 @@ impl SystemColor {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Color {
     foreground: Option<String>,
     background: Option<String>,
@@ -71,39 +97,88 @@ impl Color {
     pub fn xterm(code: u8, context: Context) -> Self {
         match context {
             Context::Foreground => Color::fg(format!("38;5;{}", code)),
-            Context::Background => Color::bg(format!("38;5;{}", code)),
-            Context::Solid => Color::new(format!("38;5;{}", code), format!("38;5;{}", code)),
+            Context::Background => Color::bg(format!("48;5;{}", code)),
+            Context::Solid => Color::new(format!("38;5;{}", code), format!("48;5;{}", code)),
         }
     }
 
     pub fn rgb(r: u8, g: u8, b: u8, context: Context) -> Self {
         match context {
             Context::Foreground => Color::fg(format!("38;2;{};{};{}", r, g, b)),
-            Context::Background => Color::bg(format!("38;2;{};{};{}", r, g, b)),
+            Context::Background => Color::bg(format!("48;2;{};{};{}", r, g, b)),
             Context::Solid => Color::new(
-                format!("38;5;{};{};{}", r, g, b),
-                format!("38;5;{};{};{}", r, g, b),
+                format!("38;2;{};{};{}", r, g, b),
+                format!("48;2;{};{};{}", r, g, b),
             ),
         }
     }
 
     pub fn system(system: SystemColor, context: Context) -> Self {
         match context {
-            Context::Foreground => Color::fg(format!("\x1b[{}m", 30 + system.color())),
-            Context::Background => Color::bg(format!("\x1b[{}m", 40 + system.color())),
-            Context::Solid => Color::new(
-                format!("\x1b[{}m", 30 + system.color()),
-                format!("\x1b[{}m", 40 + system.color()),
-            ),
+            Context::Foreground => Color::fg(format!("{}", 30 + system.color())),
+            Context::Background => Color::bg(format!("{}", 40 + system.color())),
+            Context::Solid => Color::new(30 + system.color(), 40 + system.color()),
+        }
+    }
+
+    /// Layer this color on top of `below`, keeping the background of `below` when
+    /// this color doesn't set one
+    pub fn over(self, below: &Color) -> Color {
+        Color {
+            background: self.background.or_else(|| below.background.clone()),
+            ..self
+        }
+    }
+
+    /// The foreground and background as [`Rgb`], or `None` where the terminal's
+    /// default is used
+    ///
+    /// System and xterm colors use the standard xterm palette, which may differ from
+    /// the colors the terminal actually shows.
+    pub fn to_rgb(&self) -> (Option<Rgb>, Option<Rgb>) {
+        let codes = [&self.foreground, &self.background]
+            .into_iter()
+            .flatten()
+            .flat_map(|part| part.split(';'))
+            .map(|code| code.parse::<u8>().unwrap_or(0))
+            .collect::<Vec<_>>();
+
+        let (mut foreground, mut background) = (None, None);
+        let mut codes = codes.into_iter();
+        while let Some(code) = codes.next() {
+            let target = match code {
+                0 => {
+                    (foreground, background) = (None, None);
+                    continue;
+                }
+                30..=37 | 90..=97 | 39 | 38 => &mut foreground,
+                40..=47 | 100..=107 | 49 | 48 => &mut background,
+                _ => continue,
+            };
+            *target = match code {
+                30..=37 | 40..=47 => Some(Rgb::xterm(code % 10)),
+                90..=97 | 100..=107 => Some(Rgb::xterm(code % 10 + 8)),
+                38 | 48 => match codes.next() {
+                    Some(5) => codes.next().map(Rgb::xterm),
+                    Some(2) => {
+                        let mut channel = || codes.next().unwrap_or(0);
+                        Some(Rgb(channel(), channel(), channel()))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            };
         }
+        (foreground, background)
     }
 }
 
 impl Default for Color {
+    /// Reset the colors and any text attributes back to the terminal's defaults
     fn default() -> Self {
         Color {
-            foreground: Some("\x1b[39m".to_owned()),
-            background: Some("\x1b[49m".to_owned()),
+            foreground: Some("0".to_owned()),
+            background: None,
         }
     }
 }
@@ -119,9 +194,370 @@ impl Display for Color {
             parts.push(background.clone());
         }
 
-        if parts.len() > 0 {
+        if !parts.is_empty() {
             write!(f, "\x1b[{}m", parts.join(";"))?;
         }
         Ok(())
     }
 }
+
+/// A color stored as red, green, and blue channels
+///
+/// Unlike [`Color`], the channels can be read back which allows colors to be mixed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    /// A color from the xterm 256 color palette
+    pub fn xterm(code: u8) -> Rgb {
+        const SYSTEM: [(u8, u8, u8); 16] = [
+            (0, 0, 0),
+            (205, 0, 0),
+            (0, 205, 0),
+            (205, 205, 0),
+            (0, 0, 238),
+            (205, 0, 205),
+            (0, 205, 205),
+            (229, 229, 229),
+            (127, 127, 127),
+            (255, 0, 0),
+            (0, 255, 0),
+            (255, 255, 0),
+            (92, 92, 255),
+            (255, 0, 255),
+            (0, 255, 255),
+            (255, 255, 255),
+        ];
+
+        match code {
+            0..=15 => SYSTEM[code as usize].into(),
+            16..=231 => {
+                let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+                let cube = code - 16;
+                Rgb(level(cube / 36), level(cube / 6 % 6), level(cube % 6))
+            }
+            _ => {
+                let gray = 8 + (code - 232) * 10;
+                Rgb(gray, gray, gray)
+            }
+        }
+    }
+
+    /// Blend towards `other` by `t`, where `0.0` is this color and `1.0` is `other`
+    pub fn lerp(&self, other: &Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0., 1.);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+
+    /// Blend towards `other` by `t` in the [`Oklab`] color space, which keeps the
+    /// brightness even and avoids the muddy middle of a plain RGB blend
+    pub fn lerp_oklab(&self, other: &Rgb, t: f64) -> Rgb {
+        Oklab::from(*self).lerp(&Oklab::from(*other), t).into()
+    }
+
+    pub fn color(&self, context: Context) -> Color {
+        Color::rgb(self.0, self.1, self.2, context)
+    }
+}
+
+impl From<(u8, u8, u8)> for Rgb {
+    fn from(value: (u8, u8, u8)) -> Self {
+        Rgb(value.0, value.1, value.2)
+    }
+}
+
+/// A color in the Oklab perceptual color space
+///
+/// Equal steps between colors look like equal changes to the eye, which makes it
+/// better than [`Rgb`] for fades and gradients. `l` is lightness from `0.0` to `1.0`,
+/// while `a` and `b` run from green to red and from blue to yellow.
+///
+/// # Example
+/// ```
+/// use clige::core::color::{Oklab, Rgb};
+///
+/// let orange = Oklab::from(Rgb(255, 128, 0));
+/// assert_eq!(Rgb::from(orange), Rgb(255, 128, 0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Oklab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+impl Oklab {
+    /// Blend towards `other` by `t`, where `0.0` is this color and `1.0` is `other`
+    pub fn lerp(&self, other: &Oklab, t: f64) -> Oklab {
+        let t = t.clamp(0., 1.);
+        Oklab {
+            l: self.l + (other.l - self.l) * t,
+            a: self.a + (other.a - self.a) * t,
+            b: self.b + (other.b - self.b) * t,
+        }
+    }
+}
+
+impl From<Rgb> for Oklab {
+    fn from(Rgb(r, g, b): Rgb) -> Self {
+        let linear = |channel: u8| {
+            let c = channel as f64 / 255.;
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        let (r, g, b) = (linear(r), linear(g), linear(b));
+
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+        Oklab {
+            l: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            a: 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            b: 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        }
+    }
+}
+
+impl From<Oklab> for Rgb {
+    fn from(Oklab { l, a, b }: Oklab) -> Self {
+        let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+        let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+        let s_ = (l - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+        let r = 4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_;
+        let g = -1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_;
+        let b = -0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_;
+
+        let gamma = |c: f64| {
+            let c = if c <= 0.0031308 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1. / 2.4) - 0.055
+            };
+            (c.clamp(0., 1.) * 255.).round() as u8
+        };
+        Rgb(gamma(r), gamma(g), gamma(b))
+    }
+}
+
+/// Colors spread over the range `0.0` to `1.0` that can be sampled at any point
+///
+/// # Example
+/// ```
+/// use clige::core::color::{Gradient, Rgb};
+///
+/// let health = Gradient::new(vec![
+///     (0.0, Rgb(255, 0, 0)),
+///     (0.5, Rgb(255, 255, 0)),
+///     (1.0, Rgb(0, 255, 0)),
+/// ]);
+/// assert_eq!(health.at(0.25), Rgb(255, 128, 0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Gradient {
+    stops: Vec<(f64, Rgb)>,
+}
+
+impl Gradient {
+    /// Create a gradient from `(position, color)` stops, which are sorted by position
+    pub fn new(mut stops: Vec<(f64, Rgb)>) -> Self {
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Gradient { stops }
+    }
+
+    /// Color at a position, clamped to the first and last stop
+    pub fn at(&self, position: f64) -> Rgb {
+        let after = self.stops.partition_point(|(p, _)| *p <= position);
+        match (after.checked_sub(1), self.stops.get(after)) {
+            (None, Some((_, first))) => *first,
+            (Some(index), None) => self.stops[index].1,
+            (Some(index), Some((end, to))) => {
+                let (start, from) = self.stops[index];
+                from.lerp(to, (position - start) / (end - start))
+            }
+            (None, None) => Rgb::default(),
+        }
+    }
+}
+
+/// A terminal color used for the foreground or background of a [`Style`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tone {
+    System(SystemColor),
+    /// The bright variant of a system color
+    Bright(SystemColor),
+    Xterm(u8),
+    Rgb(Rgb),
+}
+
+impl Tone {
+    fn code(&self, background: bool) -> String {
+        let offset = if background { 10 } else { 0 };
+        match self {
+            Tone::System(system) => format!("{}", 30 + offset + system.color()),
+            Tone::Bright(system) => format!("{}", 90 + offset + system.color()),
+            Tone::Xterm(code) => format!("{};5;{}", 38 + offset, code),
+            Tone::Rgb(Rgb(r, g, b)) => format!("{};2;{};{};{}", 38 + offset, r, g, b),
+        }
+    }
+
+    /// Parse a color name like `red` or `bright-red`, an xterm code from `0` to `255`,
+    /// or a hex color like `#ff8700`
+    pub fn parse(value: &str) -> Option<Tone> {
+        let value = value.trim().to_lowercase();
+        if let Some(hex) = value.strip_prefix('#') {
+            // Checked up front so slicing can't split a multi-byte character
+            if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return None;
+            }
+            let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+            return Some(Tone::Rgb(Rgb(channel(0)?, channel(2)?, channel(4)?)));
+        }
+
+        if let Ok(code) = value.parse::<u8>() {
+            return Some(Tone::Xterm(code));
+        }
+
+        let (bright, name) = match value.strip_prefix("bright-") {
+            Some(name) => (true, name),
+            None => (false, value.as_str()),
+        };
+        let system = *SystemColor::ALL.iter().find(|c| c.name() == name)?;
+        Some(match bright {
+            true => Tone::Bright(system),
+            false => Tone::System(system),
+        })
+    }
+}
+
+impl From<Rgb> for Tone {
+    fn from(value: Rgb) -> Self {
+        Tone::Rgb(value)
+    }
+}
+
+impl From<SystemColor> for Tone {
+    fn from(value: SystemColor) -> Self {
+        Tone::System(value)
+    }
+}
+
+/// Colors and text attributes
+///
+/// Colors that are `None` use the terminal's default.
+///
+/// # Example
+/// ```
+/// use clige::core::color::{Color, Rgb, Style, SystemColor};
+///
+/// let warning: Color = Style::new().fg(SystemColor::Yellow).bold(true).into();
+/// let sky: Color = Style::new().bg(Rgb(135, 206, 235)).into();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Style {
+    pub fg: Option<Tone>,
+    pub bg: Option<Tone>,
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+    /// Swap the foreground and background
+    pub reverse: bool,
+}
+
+impl Style {
+    pub fn new() -> Self {
+        Style::default()
+    }
+
+    pub fn fg<T: Into<Tone>>(self, fg: T) -> Self {
+        Style {
+            fg: Some(fg.into()),
+            ..self
+        }
+    }
+
+    pub fn bg<T: Into<Tone>>(self, bg: T) -> Self {
+        Style {
+            bg: Some(bg.into()),
+            ..self
+        }
+    }
+
+    pub fn bold(self, bold: bool) -> Self {
+        Style { bold, ..self }
+    }
+
+    pub fn dim(self, dim: bool) -> Self {
+        Style { dim, ..self }
+    }
+
+    pub fn italic(self, italic: bool) -> Self {
+        Style { italic, ..self }
+    }
+
+    pub fn underline(self, underline: bool) -> Self {
+        Style { underline, ..self }
+    }
+
+    pub fn reverse(self, reverse: bool) -> Self {
+        Style { reverse, ..self }
+    }
+
+    /// Layer `other` on top of this style
+    ///
+    /// Colors set in `other` replace these colors and attributes from both are kept.
+    pub fn patch(self, other: Style) -> Style {
+        Style {
+            fg: other.fg.or(self.fg),
+            bg: other.bg.or(self.bg),
+            bold: self.bold || other.bold,
+            dim: self.dim || other.dim,
+            italic: self.italic || other.italic,
+            underline: self.underline || other.underline,
+            reverse: self.reverse || other.reverse,
+        }
+    }
+
+    /// Build the color for the style
+    ///
+    /// The color always starts by resetting attributes, so nothing carries over
+    /// from the pixel that was drawn before it.
+    pub fn color(&self) -> Color {
+        let mut foreground = vec!["0".to_string()];
+        let attributes = [
+            (self.bold, "1"),
+            (self.dim, "2"),
+            (self.italic, "3"),
+            (self.underline, "4"),
+            (self.reverse, "7"),
+        ];
+        foreground.extend(
+            attributes
+                .iter()
+                .filter(|(on, _)| *on)
+                .map(|(_, code)| code.to_string()),
+        );
+        foreground.extend(self.fg.map(|fg| fg.code(false)));
+
+        Color {
+            foreground: Some(foreground.join(";")),
+            background: self.bg.map(|bg| bg.code(true)),
+        }
+    }
+}
+
+impl From<Style> for Color {
+    fn from(value: Style) -> Self {
+        value.color()
+    }
+}
@@ -1,15 +1,35 @@
+use serde::{Deserialize, Serialize};
+use unicode_width::UnicodeWidthChar;
+
 use super::color::Color;
 
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Pixel {
     pub value: char,
     pub color: Color,
 }
 
 impl Pixel {
+    /// Value stored in the cell that follows a double width character.
+    ///
+    /// These cells are covered by the wide character and are skipped when rendering.
+    pub const CONTINUATION: char = '\0';
+
     pub fn new(value: char, color: Color) -> Self {
         Pixel { color, value }
     }
+
+    /// Number of terminal columns the pixel's character occupies
+    pub fn width(&self) -> usize {
+        char_width(self.value)
+    }
+}
+
+/// Number of terminal columns a character occupies.
+///
+/// Control characters are treated as having no width.
+pub fn char_width(value: char) -> usize {
+    value.width().unwrap_or(0)
 }
 
 impl From<char> for Pixel {
@@ -27,7 +47,7 @@ impl Default for Pixel {
     }
 }
 
-#[derive(Debug, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
 pub struct Rect {
     pub left: usize,
     pub top: usize,
@@ -37,19 +57,14 @@ pub struct Rect {
 
 impl Rect {
     pub fn new() -> Rect {
-        Rect {
-            left: 0,
-            top: 0,
-            right: 0,
-            bottom: 0
-        }
+        Rect::default()
     }
 
     pub fn clamp(&mut self, width: usize, height: usize) {
-        self.left = self.left.min(width).max(0);
-        self.right = self.right.min(width).max(0);
-        self.top = self.top.min(height).max(0);
-        self.bottom = self.bottom.min(height).max(0);
+        self.left = self.left.min(width);
+        self.right = self.right.min(width);
+        self.top = self.top.min(height);
+        self.bottom = self.bottom.min(height);
     }
 
     pub fn width(&self) -> usize {
@@ -59,6 +74,61 @@ impl Rect {
     pub fn height(&self) -> usize {
         self.bottom - self.top
     }
+
+    /// Whether the position is inside of the rect
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x >= self.left && x < self.right && y >= self.top && y < self.bottom
+    }
+
+    /// Whether the rects share any cells. Empty rects never overlap anything
+    pub fn overlaps(&self, other: &Rect) -> bool {
+        self.left < other.right
+            && other.left < self.right
+            && self.top < other.bottom
+            && other.top < self.bottom
+    }
+
+    /// Whether `other` is entirely inside of the rect
+    pub fn encloses(&self, other: &Rect) -> bool {
+        other.left >= self.left
+            && other.right <= self.right
+            && other.top >= self.top
+            && other.bottom <= self.bottom
+    }
+
+    /// The overlapping part of two rects, which is empty when they don't overlap
+    pub fn intersect(&self, other: &Rect) -> Rect {
+        let left = self.left.max(other.left);
+        let top = self.top.max(other.top);
+        Rect {
+            left,
+            top,
+            right: self.right.min(other.right).max(left),
+            bottom: self.bottom.min(other.bottom).max(top),
+        }
+    }
+
+    /// Move the rect so its top left corner is offset by `x` and `y`
+    pub fn offset(&self, x: usize, y: usize) -> Rect {
+        Rect {
+            left: self.left + x,
+            top: self.top + y,
+            right: self.right + x,
+            bottom: self.bottom + y,
+        }
+    }
+
+    /// Move every side inward by `amount`, stopping when the sides meet
+    pub fn shrink(&self, amount: usize) -> Rect {
+        let horizontal = amount.min(self.width() / 2);
+        let vertical = amount.min(self.height() / 2);
+        Rect {
+            left: self.left + horizontal,
+            top: self.top + vertical,
+            right: self.right - horizontal,
+            bottom: self.bottom - vertical,
+        }
+    }
 }
 
 impl From<[usize; 1]> for Rect {
@@ -1,5 +1,6 @@
 pub mod buffer;
 pub mod data;
+pub mod event;
 
 pub mod color;
 
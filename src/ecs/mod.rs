@@ -0,0 +1,16 @@
+//! Entity component system
+//!
+//! Entities are generational handles, components are any `'static` type stored per
+//! entity in a [`World`], and systems are functions over the world run in the
+//! stages of a [`Schedule`].
+
+mod entity;
+mod query;
+mod schedule;
+mod storage;
+mod world;
+
+pub use entity::Entity;
+pub use query::{Query, QueryMut};
+pub use schedule::{Schedule, Stage, Time};
+pub use world::World;
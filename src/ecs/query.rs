@@ -0,0 +1,212 @@
+use std::{any::TypeId, marker::PhantomData};
+
+use super::{
+    entity::Entity,
+    storage::{AnyStorage, Storage},
+    world::World,
+};
+
+/// Component types an entity must have, must not have, or must have changed
+#[derive(Debug, Clone, Default)]
+struct Filter {
+    with: Vec<TypeId>,
+    without: Vec<TypeId>,
+    changed: Vec<TypeId>,
+}
+
+impl Filter {
+    fn matches(&self, world: &World, entity: Entity) -> bool {
+        let has = |id: &TypeId| world.any_storage(id).is_some_and(|s| s.contains(entity));
+        self.with.iter().all(has)
+            && !self.without.iter().any(has)
+            && self.changed.iter().all(|id| {
+                world
+                    .any_storage(id)
+                    .is_some_and(|s| s.changed_since(entity, world.last_run()))
+            })
+    }
+}
+
+/// Entities with a component of type `T`, narrowed down by filters
+///
+/// Created with [`World::query`].
+pub struct Query<'w, T> {
+    world: &'w World,
+    filter: Filter,
+    component: PhantomData<T>,
+}
+
+impl<'w, T: 'static> Query<'w, T> {
+    pub(crate) fn new(world: &'w World) -> Self {
+        Query {
+            world,
+            filter: Filter::default(),
+            component: PhantomData,
+        }
+    }
+
+    /// Only match entities that also have a `U`
+    pub fn with<U: 'static>(mut self) -> Self {
+        self.filter.with.push(TypeId::of::<U>());
+        self
+    }
+
+    /// Only match entities that don't have a `U`
+    pub fn without<U: 'static>(mut self) -> Self {
+        self.filter.without.push(TypeId::of::<U>());
+        self
+    }
+
+    /// Only match entities whose `U` was added or changed since the current system last ran
+    pub fn changed<U: 'static>(mut self) -> Self {
+        self.filter.changed.push(TypeId::of::<U>());
+        self
+    }
+
+    /// Each matching entity with its component, in entity index order
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &'w T)> + '_ {
+        let world = self.world;
+        world
+            .any_storage(&TypeId::of::<T>())
+            .and_then(|storage| storage.as_any().downcast_ref::<Storage<T>>())
+            .into_iter()
+            .flat_map(|storage| storage.iter())
+            .filter_map(move |(index, value)| {
+                let entity = world.entity_at(index)?;
+                self.filter
+                    .matches(world, entity)
+                    .then_some((entity, value))
+            })
+    }
+
+    pub fn entities(&self) -> Vec<Entity> {
+        self.iter().map(|(entity, _)| entity).collect()
+    }
+
+    pub fn count(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// The only matching entity, or `None` if there are none or more than one
+    pub fn single(&self) -> Option<(Entity, &'w T)> {
+        let mut matches = self.iter();
+        match (matches.next(), matches.next()) {
+            (Some(single), None) => Some(single),
+            _ => None,
+        }
+    }
+}
+
+/// Entities with a component of type `T` that is visited mutably
+///
+/// Created with [`World::query_mut`]. Each visited component is marked as changed.
+pub struct QueryMut<'w, T> {
+    world: &'w mut World,
+    filter: Filter,
+    component: PhantomData<T>,
+}
+
+impl<'w, T: 'static> QueryMut<'w, T> {
+    pub(crate) fn new(world: &'w mut World) -> Self {
+        QueryMut {
+            world,
+            filter: Filter::default(),
+            component: PhantomData,
+        }
+    }
+
+    /// Only match entities that also have a `U`
+    pub fn with<U: 'static>(mut self) -> Self {
+        self.filter.with.push(TypeId::of::<U>());
+        self
+    }
+
+    /// Only match entities that don't have a `U`
+    pub fn without<U: 'static>(mut self) -> Self {
+        self.filter.without.push(TypeId::of::<U>());
+        self
+    }
+
+    /// Only match entities whose `U` was added or changed since the current system last ran
+    pub fn changed<U: 'static>(mut self) -> Self {
+        self.filter.changed.push(TypeId::of::<U>());
+        self
+    }
+
+    /// Call `f` with each matching entity, its component, and the rest of the world
+    ///
+    /// Components of type `T` can't be read through the world inside of `f`.
+    pub fn for_each<F>(self, mut f: F)
+    where
+        F: FnMut(Entity, &mut T, &World),
+    {
+        let own = TypeId::of::<T>();
+        if self.filter.without.contains(&own) {
+            return;
+        }
+        let Some(storage) = self.world.take_storage::<T>() else {
+            return;
+        };
+        let mut taken = Taken::<T> {
+            world: self.world,
+            storage: Some(storage),
+            component: PhantomData,
+        };
+
+        // Filters on `T` itself are checked against the storage, as it is out of the world
+        let own_changed = self.filter.changed.contains(&own);
+        let filter = Filter {
+            with: self
+                .filter
+                .with
+                .into_iter()
+                .filter(|id| *id != own)
+                .collect(),
+            without: self.filter.without,
+            changed: self
+                .filter
+                .changed
+                .into_iter()
+                .filter(|id| *id != own)
+                .collect(),
+        };
+
+        let world = &*taken.world;
+        let storage = taken.storage.as_mut().unwrap();
+        let (tick, last_run) = (world.tick(), world.last_run());
+        let typed = storage.as_any_mut().downcast_mut::<Storage<T>>().unwrap();
+        let indexes = typed.iter().map(|(index, _)| index).collect::<Vec<_>>();
+
+        for index in indexes {
+            let Some(entity) = world.entity_at(index) else {
+                continue;
+            };
+            if !filter.matches(world, entity)
+                || (own_changed && !storage.changed_since(entity, last_run))
+            {
+                continue;
+            }
+
+            let typed = storage.as_any_mut().downcast_mut::<Storage<T>>().unwrap();
+            if let Some(value) = typed.get_mut(entity, tick) {
+                f(entity, value, world);
+            }
+        }
+    }
+}
+
+/// A storage taken out of the world, put back when dropped so a panic in a query
+/// doesn't lose it
+struct Taken<'w, T: 'static> {
+    world: &'w mut World,
+    storage: Option<Box<dyn AnyStorage>>,
+    component: PhantomData<T>,
+}
+
+impl<T: 'static> Drop for Taken<'_, T> {
+    fn drop(&mut self) {
+        if let Some(storage) = self.storage.take() {
+            self.world.return_storage::<T>(storage);
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use super::world::World;
+
+/// Where in the game loop a system runs
+///
+/// Every update system runs before any render system each frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum Stage {
+    /// Game logic, which can use [`Time::delta`] to move at the same speed at any frame rate
+    #[default]
+    Update,
+    /// Drawing the state of the world into a buffer
+    Render,
+}
+
+/// Resource holding frame timing, updated by [`Schedule::run`] before any system runs
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Time {
+    /// Seconds since the previous frame
+    pub delta: f64,
+    /// Seconds since the first frame
+    pub elapsed: f64,
+    /// Number of frames run before this one
+    pub frame: u64,
+}
+
+struct System {
+    name: String,
+    stage: Stage,
+    run: Box<dyn FnMut(&mut World)>,
+    last_run: u64,
+}
+
+/// Systems grouped into the stages of the game loop
+///
+/// Systems in a stage run in the order they were added. Each system sees the
+/// components changed since it last ran through [`Query::changed`](super::Query::changed).
+///
+/// # Example
+/// ```
+/// use clige::ecs::{Schedule, Stage, Time, World};
+///
+/// struct Score(u32);
+///
+/// let mut world = World::new();
+/// world.insert_resource(Score(0));
+///
+/// let mut schedule = Schedule::new()
+///     .system(Stage::Update, "score", |world| {
+///         world.resource_mut::<Score>().unwrap().0 += 1;
+///     })
+///     .system(Stage::Render, "draw", |world| {
+///         let _time = world.resource::<Time>().unwrap();
+///     });
+///
+/// schedule.run(&mut world, 1. / 30.);
+/// schedule.run(&mut world, 1. / 30.);
+/// assert_eq!(world.resource::<Score>().unwrap().0, 2);
+/// ```
+#[derive(Default)]
+pub struct Schedule {
+    systems: Vec<System>,
+}
+
+impl Schedule {
+    pub fn new() -> Self {
+        Schedule::default()
+    }
+
+    /// Add a system to a stage
+    pub fn system<S, F>(mut self, stage: Stage, name: S, system: F) -> Self
+    where
+        S: Into<String>,
+        F: FnMut(&mut World) + 'static,
+    {
+        self.add(stage, name, system);
+        self
+    }
+
+    /// Add a system to a stage
+    pub fn add<S, F>(&mut self, stage: Stage, name: S, system: F)
+    where
+        S: Into<String>,
+        F: FnMut(&mut World) + 'static,
+    {
+        self.systems.push(System {
+            name: name.into(),
+            stage,
+            run: Box::new(system),
+            last_run: 0,
+        });
+    }
+
+    /// Remove every system with a name. Returns `false` if there were none
+    pub fn remove(&mut self, name: &str) -> bool {
+        let count = self.systems.len();
+        self.systems.retain(|system| system.name != name);
+        self.systems.len() != count
+    }
+
+    /// Names of the systems in a stage in the order they run
+    pub fn systems(&self, stage: Stage) -> Vec<&str> {
+        self.systems
+            .iter()
+            .filter(|system| system.stage == stage)
+            .map(|system| system.name.as_str())
+            .collect()
+    }
+
+    /// Run every system in a stage
+    pub fn run_stage(&mut self, world: &mut World, stage: Stage) {
+        for system in self.systems.iter_mut().filter(|s| s.stage == stage) {
+            system.last_run = world.begin_system(system.last_run);
+            (system.run)(world);
+            world.end_system();
+        }
+    }
+
+    /// Run one frame: advance [`Time`] by `delta` seconds, then run the update and render stages
+    pub fn run(&mut self, world: &mut World, delta: f64) {
+        match world.resource_mut::<Time>() {
+            Some(time) => {
+                time.delta = delta;
+                time.elapsed += delta;
+                time.frame += 1;
+            }
+            None => {
+                world.insert_resource(Time {
+                    delta,
+                    elapsed: delta,
+                    frame: 0,
+                });
+            }
+        }
+
+        self.run_stage(world, Stage::Update);
+        self.run_stage(world, Stage::Render);
+    }
+}
@@ -0,0 +1,107 @@
+use std::any::Any;
+
+use super::entity::Entity;
+
+#[derive(Debug, Clone)]
+struct Slot<T> {
+    value: T,
+    changed: u64,
+}
+
+/// Components of a single type, indexed by entity index
+///
+/// The world removes an entity's components when it is despawned, so a slot always
+/// belongs to the living entity with that index.
+#[derive(Debug, Clone)]
+pub(crate) struct Storage<T> {
+    slots: Vec<Option<Slot<T>>>,
+}
+
+impl<T> Default for Storage<T> {
+    fn default() -> Self {
+        Storage { slots: Vec::new() }
+    }
+}
+
+impl<T> Storage<T> {
+    pub fn insert(&mut self, entity: Entity, value: T, tick: u64) -> Option<T> {
+        let index = entity.index();
+        if index >= self.slots.len() {
+            self.slots.resize_with(index + 1, || None);
+        }
+        self.slots[index]
+            .replace(Slot {
+                value,
+                changed: tick,
+            })
+            .map(|slot| slot.value)
+    }
+
+    pub fn get(&self, entity: Entity) -> Option<&T> {
+        self.slots
+            .get(entity.index())?
+            .as_ref()
+            .map(|slot| &slot.value)
+    }
+
+    /// Get a component and mark it as changed at `tick`
+    pub fn get_mut(&mut self, entity: Entity, tick: u64) -> Option<&mut T> {
+        let slot = self.slots.get_mut(entity.index())?.as_mut()?;
+        slot.changed = tick;
+        Some(&mut slot.value)
+    }
+
+    pub fn take(&mut self, entity: Entity) -> Option<T> {
+        self.slots
+            .get_mut(entity.index())?
+            .take()
+            .map(|slot| slot.value)
+    }
+
+    /// Indexes that have a component along with it
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| Some((index, &slot.as_ref()?.value)))
+    }
+}
+
+/// The parts of a [`Storage`] that don't depend on its component type
+pub(crate) trait AnyStorage: Any {
+    fn contains(&self, entity: Entity) -> bool;
+
+    /// Whether the entity's component was added or changed after `tick`
+    fn changed_since(&self, entity: Entity, tick: u64) -> bool;
+
+    fn remove(&mut self, entity: Entity);
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: 'static> AnyStorage for Storage<T> {
+    fn contains(&self, entity: Entity) -> bool {
+        self.get(entity).is_some()
+    }
+
+    fn changed_since(&self, entity: Entity, tick: u64) -> bool {
+        self.slots
+            .get(entity.index())
+            .and_then(|slot| slot.as_ref())
+            .is_some_and(|slot| slot.changed > tick)
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        self.take(entity);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
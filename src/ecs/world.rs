@@ -0,0 +1,254 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+};
+
+use super::{
+    entity::{Entities, Entity},
+    query::{Query, QueryMut},
+    storage::{AnyStorage, Storage},
+};
+
+/// Entities, their components, and resources shared by every system
+///
+/// Any `'static` type can be a component or a resource. Getting a component
+/// mutably marks it as changed, which [`Query::changed`] can filter on.
+///
+/// # Example
+/// ```
+/// use clige::ecs::World;
+///
+/// struct Position(i32, i32);
+/// struct Velocity(i32, i32);
+///
+/// let mut world = World::new();
+/// let player = world.spawn();
+/// world.insert(player, Position(0, 0)).unwrap();
+/// world.insert(player, Velocity(1, 2)).unwrap();
+///
+/// world.query_mut::<Position>().with::<Velocity>().for_each(|entity, position, world| {
+///     let velocity = world.get::<Velocity>(entity).unwrap();
+///     position.0 += velocity.0;
+///     position.1 += velocity.1;
+/// });
+///
+/// assert_eq!(world.get::<Position>(player).map(|p| (p.0, p.1)), Some((1, 2)));
+/// ```
+pub struct World {
+    entities: Entities,
+    storages: HashMap<TypeId, Box<dyn AnyStorage>>,
+    resources: HashMap<TypeId, Box<dyn Any>>,
+    /// Tick that changes are currently recorded at
+    tick: u64,
+    /// Changes made after this tick count as changed
+    last_run: u64,
+}
+
+impl World {
+    pub fn new() -> Self {
+        World {
+            entities: Entities::default(),
+            storages: HashMap::new(),
+            resources: HashMap::new(),
+            tick: 1,
+            last_run: 0,
+        }
+    }
+
+    /// Create a new entity without any components
+    pub fn spawn(&mut self) -> Entity {
+        self.entities.allocate()
+    }
+
+    /// Remove an entity and all of its components. Returns `false` if it was already dead
+    pub fn despawn(&mut self, entity: Entity) -> bool {
+        if !self.entities.free(entity) {
+            return false;
+        }
+        for storage in self.storages.values_mut() {
+            storage.remove(entity);
+        }
+        true
+    }
+
+    pub fn is_alive(&self, entity: Entity) -> bool {
+        self.entities.is_alive(entity)
+    }
+
+    /// Number of living entities
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.len() == 0
+    }
+
+    /// Every living entity
+    pub fn entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.entities.iter()
+    }
+
+    fn storage<T: 'static>(&self) -> Option<&Storage<T>> {
+        self.storages
+            .get(&TypeId::of::<T>())?
+            .as_any()
+            .downcast_ref::<Storage<T>>()
+    }
+
+    fn storage_mut<T: 'static>(&mut self) -> Option<&mut Storage<T>> {
+        self.storages
+            .get_mut(&TypeId::of::<T>())?
+            .as_any_mut()
+            .downcast_mut::<Storage<T>>()
+    }
+
+    pub(crate) fn any_storage(&self, id: &TypeId) -> Option<&dyn AnyStorage> {
+        self.storages.get(id).map(|storage| storage.as_ref())
+    }
+
+    /// Take a storage out of the world so it can be mutated while the rest of the world is read
+    pub(crate) fn take_storage<T: 'static>(&mut self) -> Option<Box<dyn AnyStorage>> {
+        self.storages.remove(&TypeId::of::<T>())
+    }
+
+    pub(crate) fn return_storage<T: 'static>(&mut self, storage: Box<dyn AnyStorage>) {
+        self.storages.insert(TypeId::of::<T>(), storage);
+    }
+
+    /// Add a component to an entity, replacing any component of the same type
+    pub fn insert<T: 'static>(
+        &mut self,
+        entity: Entity,
+        component: T,
+    ) -> Result<Option<T>, String> {
+        if !self.is_alive(entity) {
+            return Err(format!("{} is not alive", entity));
+        }
+
+        let tick = self.tick;
+        let storage = self
+            .storages
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::<Storage<T>>::default())
+            .as_any_mut()
+            .downcast_mut::<Storage<T>>()
+            .unwrap();
+        Ok(storage.insert(entity, component, tick))
+    }
+
+    /// Remove a component from an entity, returning it
+    pub fn remove<T: 'static>(&mut self, entity: Entity) -> Option<T> {
+        if !self.is_alive(entity) {
+            return None;
+        }
+        self.storage_mut::<T>()?.take(entity)
+    }
+
+    pub fn get<T: 'static>(&self, entity: Entity) -> Option<&T> {
+        if !self.is_alive(entity) {
+            return None;
+        }
+        self.storage::<T>()?.get(entity)
+    }
+
+    /// Mutably get a component, marking it as changed
+    pub fn get_mut<T: 'static>(&mut self, entity: Entity) -> Option<&mut T> {
+        if !self.is_alive(entity) {
+            return None;
+        }
+        let tick = self.tick;
+        self.storage_mut::<T>()?.get_mut(entity, tick)
+    }
+
+    pub fn has<T: 'static>(&self, entity: Entity) -> bool {
+        self.get::<T>(entity).is_some()
+    }
+
+    /// Whether an entity's component was added or changed since the current system last ran
+    pub fn is_changed<T: 'static>(&self, entity: Entity) -> bool {
+        self.is_alive(entity)
+            && self
+                .any_storage(&TypeId::of::<T>())
+                .is_some_and(|storage| storage.changed_since(entity, self.last_run))
+    }
+
+    /// Find entities with a component, narrowed down by filters
+    pub fn query<T: 'static>(&self) -> Query<'_, T> {
+        Query::new(self)
+    }
+
+    /// Like [`World::query`], but visits each matching component mutably
+    pub fn query_mut<T: 'static>(&mut self) -> QueryMut<'_, T> {
+        QueryMut::new(self)
+    }
+
+    pub(crate) fn entity_at(&self, index: usize) -> Option<Entity> {
+        self.entities.at(index)
+    }
+
+    pub(crate) fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    pub(crate) fn last_run(&self) -> u64 {
+        self.last_run
+    }
+
+    /// Start recording changes for a system that last ran at `last_run`
+    ///
+    /// Returns the tick the system runs at, which becomes its next `last_run`.
+    pub(crate) fn begin_system(&mut self, last_run: u64) -> u64 {
+        self.last_run = last_run;
+        self.tick += 1;
+        self.tick
+    }
+
+    /// Stop recording changes for the current system, so changes made after it
+    /// count as new to it
+    pub(crate) fn end_system(&mut self) {
+        self.tick += 1;
+    }
+
+    /// Treat everything changed so far as seen, for worlds updated without a
+    /// [`Schedule`](super::Schedule)
+    pub fn clear_changes(&mut self) {
+        self.last_run = self.tick;
+        self.tick += 1;
+    }
+
+    /// Add a resource, replacing any resource of the same type
+    pub fn insert_resource<R: 'static>(&mut self, resource: R) -> Option<R> {
+        self.resources
+            .insert(TypeId::of::<R>(), Box::new(resource))
+            .and_then(|old| old.downcast::<R>().ok())
+            .map(|old| *old)
+    }
+
+    pub fn remove_resource<R: 'static>(&mut self) -> Option<R> {
+        self.resources
+            .remove(&TypeId::of::<R>())
+            .and_then(|old| old.downcast::<R>().ok())
+            .map(|old| *old)
+    }
+
+    pub fn resource<R: 'static>(&self) -> Option<&R> {
+        self.resources.get(&TypeId::of::<R>())?.downcast_ref::<R>()
+    }
+
+    pub fn resource_mut<R: 'static>(&mut self) -> Option<&mut R> {
+        self.resources
+            .get_mut(&TypeId::of::<R>())?
+            .downcast_mut::<R>()
+    }
+
+    pub fn has_resource<R: 'static>(&self) -> bool {
+        self.resources.contains_key(&TypeId::of::<R>())
+    }
+}
+
+impl Default for World {
+    fn default() -> Self {
+        World::new()
+    }
+}
@@ -0,0 +1,5 @@
+pub mod particle;
+pub mod tween;
+
+pub use particle::{Emitter, Particle};
+pub use tween::{Animation, Ease, Lerp, Parallel, Repeat, Sequence, Tween};
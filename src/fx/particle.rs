@@ -0,0 +1,374 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+use rand::Rng;
+
+use crate::{
+    core::{
+        buffer::Buffer,
+        color::{Color, Gradient, Rgb, Style},
+        data::Pixel,
+    },
+    gen::Random,
+};
+
+/// A single particle owned by an [`Emitter`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Particle {
+    pub x: f64,
+    pub y: f64,
+    /// Velocity in cells per second
+    pub velocity: (f64, f64),
+    /// Seconds since the particle was spawned
+    pub age: f64,
+    /// Seconds the particle lives for
+    pub lifetime: f64,
+}
+
+impl Particle {
+    /// How far through its life the particle is, from `0.0` when spawned to `1.0`
+    /// when it dies
+    pub fn life(&self) -> f64 {
+        if self.lifetime <= 0. {
+            return 1.;
+        }
+        (self.age / self.lifetime).clamp(0., 1.)
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.age < self.lifetime
+    }
+}
+
+/// Spawns, moves, and draws particles
+///
+/// Particles are spawned continuously at [`Emitter::rate`] and all at once with
+/// [`Emitter::burst`]. Their character and color change over their life, picked from
+/// [`Emitter::chars`] and [`Emitter::colors`].
+///
+/// # Example
+/// ```
+/// use clige::core::{buffer::{Buffer, PixelBuffer}, color::{Gradient, Rgb}};
+/// use clige::fx::Emitter;
+///
+/// let mut sparks = Emitter::new(10., 5.)
+///     .seed(7)
+///     .chars("*+.")
+///     .colors(Gradient::new(vec![(0.0, Rgb(255, 220, 80)), (1.0, Rgb(60, 0, 0))]))
+///     .lifetime(0.5, 1.0);
+/// sparks.burst(20);
+///
+/// let mut buffer = PixelBuffer::new(20, 10);
+/// sparks.update(0.1);
+/// sparks.draw(&mut buffer);
+///
+/// assert_eq!(sparks.len(), 20);
+/// sparks.update(1.0);
+/// assert!(sparks.is_empty());
+/// ```
+pub struct Emitter {
+    x: f64,
+    y: f64,
+    area: (f64, f64),
+    rate: f64,
+    lifetime: (f64, f64),
+    speed: (f64, f64),
+    direction: f64,
+    spread: f64,
+    gravity: (f64, f64),
+    drag: f64,
+    chars: Vec<char>,
+    colors: Option<Gradient>,
+    max: usize,
+    emitting: bool,
+    random: Random,
+    pending: f64,
+    particles: Vec<Particle>,
+}
+
+impl Emitter {
+    /// Create an emitter at a position that spawns nothing until given a rate or
+    /// burst, sending particles in every direction
+    pub fn new(x: f64, y: f64) -> Self {
+        Emitter {
+            x,
+            y,
+            area: (0., 0.),
+            rate: 0.,
+            lifetime: (1., 1.),
+            speed: (1., 1.),
+            direction: 0.,
+            spread: TAU,
+            gravity: (0., 0.),
+            drag: 0.,
+            chars: vec!['*'],
+            colors: None,
+            max: 1000,
+            emitting: true,
+            random: Random::new(),
+            pending: 0.,
+            particles: Vec::new(),
+        }
+    }
+
+    /// Debris flying out in every direction and falling, to be started with
+    /// [`Emitter::burst`]
+    pub fn explosion(x: f64, y: f64) -> Self {
+        Emitter::new(x, y)
+            .lifetime(0.4, 1.2)
+            .speed(4., 14.)
+            .gravity(0., 12.)
+            .drag(1.5)
+            .chars("@*+:.")
+            .colors(Gradient::new(vec![
+                (0.0, Rgb(255, 255, 200)),
+                (0.3, Rgb(255, 160, 0)),
+                (1.0, Rgb(60, 10, 0)),
+            ]))
+    }
+
+    /// Drops falling across an area `width` cells wide, starting at `(x, y)`
+    pub fn rain(x: f64, y: f64, width: f64) -> Self {
+        Emitter::new(x, y)
+            .area(width, 0.)
+            .rate(width * 2.)
+            .lifetime(1., 2.)
+            .speed(12., 16.)
+            .direction(FRAC_PI_2 + 0.15, 0.05)
+            .chars("|")
+            .colors(Gradient::new(vec![
+                (0.0, Rgb(120, 160, 255)),
+                (1.0, Rgb(60, 80, 160)),
+            ]))
+    }
+
+    /// Slow puffs rising and fading away
+    pub fn smoke(x: f64, y: f64) -> Self {
+        Emitter::new(x, y)
+            .rate(6.)
+            .lifetime(1.5, 3.)
+            .speed(1., 2.5)
+            .direction(-FRAC_PI_2, 0.8)
+            .drag(0.5)
+            .chars("@Oo°.")
+            .colors(Gradient::new(vec![
+                (0.0, Rgb(200, 200, 200)),
+                (1.0, Rgb(40, 40, 40)),
+            ]))
+    }
+
+    /// Quick, bright streaks that fall away
+    pub fn sparks(x: f64, y: f64) -> Self {
+        Emitter::new(x, y)
+            .rate(20.)
+            .lifetime(0.2, 0.6)
+            .speed(6., 12.)
+            .direction(-FRAC_PI_2, 1.)
+            .gravity(0., 20.)
+            .chars("*'.")
+            .colors(Gradient::new(vec![
+                (0.0, Rgb(255, 255, 255)),
+                (0.5, Rgb(255, 220, 0)),
+                (1.0, Rgb(120, 40, 0)),
+            ]))
+    }
+
+    /// Spawn particles anywhere in a `width` by `height` area from the emitter's
+    /// position, rather than at a single point
+    pub fn area(self, width: f64, height: f64) -> Self {
+        Emitter {
+            area: (width, height),
+            ..self
+        }
+    }
+
+    /// Particles spawned each second while emitting
+    pub fn rate(self, rate: f64) -> Self {
+        Emitter { rate, ..self }
+    }
+
+    /// Range of seconds each particle lives for
+    pub fn lifetime(self, min: f64, max: f64) -> Self {
+        Emitter {
+            lifetime: (min, max),
+            ..self
+        }
+    }
+
+    /// Range of starting speeds, in cells per second
+    pub fn speed(self, min: f64, max: f64) -> Self {
+        Emitter {
+            speed: (min, max),
+            ..self
+        }
+    }
+
+    /// Angle particles are sent towards in radians, where `0.0` is right and positive
+    /// angles turn downwards, and how far either side of it they can stray
+    pub fn direction(self, angle: f64, spread: f64) -> Self {
+        Emitter {
+            direction: angle,
+            spread: spread * 2.,
+            ..self
+        }
+    }
+
+    /// Acceleration applied to every particle, in cells per second squared. Positive
+    /// y is down
+    pub fn gravity(self, x: f64, y: f64) -> Self {
+        Emitter {
+            gravity: (x, y),
+            ..self
+        }
+    }
+
+    /// How quickly particles slow down, as a fraction of their velocity lost each second
+    pub fn drag(self, drag: f64) -> Self {
+        Emitter { drag, ..self }
+    }
+
+    /// Characters shown over each particle's life, from first to last
+    pub fn chars(self, chars: &str) -> Self {
+        let mut chars: Vec<char> = chars.chars().collect();
+        if chars.is_empty() {
+            chars.push('*');
+        }
+        Emitter { chars, ..self }
+    }
+
+    /// Colors shown over each particle's life, where `0.0` is spawning and `1.0` is dying
+    pub fn colors(self, colors: Gradient) -> Self {
+        Emitter {
+            colors: Some(colors),
+            ..self
+        }
+    }
+
+    /// Most particles alive at once. Spawning stops while the limit is reached
+    pub fn max(self, max: usize) -> Self {
+        Emitter { max, ..self }
+    }
+
+    /// Seed the emitter's random numbers so it plays out the same way each time
+    pub fn seed(self, seed: i64) -> Self {
+        Emitter {
+            random: Random::from(seed),
+            ..self
+        }
+    }
+
+    pub fn position(&self) -> (f64, f64) {
+        (self.x, self.y)
+    }
+
+    /// Move where new particles spawn. Particles already alive are not moved
+    pub fn move_to(&mut self, x: f64, y: f64) {
+        self.x = x;
+        self.y = y;
+    }
+
+    /// Start or stop spawning at the emitter's rate. Particles already alive carry on
+    pub fn set_emitting(&mut self, emitting: bool) {
+        self.emitting = emitting;
+        self.pending = 0.;
+    }
+
+    pub fn is_emitting(&self) -> bool {
+        self.emitting
+    }
+
+    pub fn particles(&self) -> &[Particle] {
+        &self.particles
+    }
+
+    pub fn len(&self) -> usize {
+        self.particles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.particles.is_empty()
+    }
+
+    /// Remove every particle
+    pub fn clear(&mut self) {
+        self.particles.clear();
+        self.pending = 0.;
+    }
+
+    /// Spawn `count` particles at once, up to the maximum
+    pub fn burst(&mut self, count: usize) {
+        for _ in 0..count.min(self.max.saturating_sub(self.particles.len())) {
+            let particle = self.spawn();
+            self.particles.push(particle);
+        }
+    }
+
+    fn spawn(&mut self) -> Particle {
+        let rng = self.random.generator();
+        let mut between = |(min, max): (f64, f64)| {
+            if min < max {
+                rng.gen_range(min..max)
+            } else {
+                min
+            }
+        };
+
+        let angle = self.direction + between((-0.5, 0.5)) * self.spread.min(TAU);
+        let speed = between(self.speed);
+        Particle {
+            x: self.x + between((0., self.area.0)),
+            y: self.y + between((0., self.area.1)),
+            velocity: (angle.cos() * speed, angle.sin() * speed),
+            age: 0.,
+            lifetime: between(self.lifetime),
+        }
+    }
+
+    /// Advance every particle by `delta` seconds, removing those that have died and
+    /// spawning new ones
+    pub fn update(&mut self, delta: f64) {
+        let (gx, gy) = self.gravity;
+        let damping = (1. - self.drag * delta).max(0.);
+        for particle in self.particles.iter_mut() {
+            let (vx, vy) = particle.velocity;
+            particle.velocity = ((vx + gx * delta) * damping, (vy + gy * delta) * damping);
+            particle.x += particle.velocity.0 * delta;
+            particle.y += particle.velocity.1 * delta;
+            particle.age += delta;
+        }
+        self.particles.retain(Particle::is_alive);
+
+        if self.emitting && self.rate > 0. {
+            self.pending += self.rate * delta;
+            let count = self.pending.floor();
+            self.pending -= count;
+            self.burst(count as usize);
+        }
+    }
+
+    /// The pixel drawn for a particle at its point in life
+    pub fn pixel(&self, particle: &Particle) -> Pixel {
+        let life = particle.life();
+        let index = ((life * self.chars.len() as f64) as usize).min(self.chars.len() - 1);
+        let color = match &self.colors {
+            Some(colors) => Style::new().fg(colors.at(life)).color(),
+            None => Color::default(),
+        };
+        Pixel::new(self.chars[index], color)
+    }
+
+    /// Draw every particle inside of the buffer, keeping the background of the cells
+    /// they land on. Newer particles are drawn over older ones
+    pub fn draw<B: Buffer<Pixel = Pixel>>(&self, buffer: &mut B) {
+        for particle in self.particles.iter() {
+            let (x, y) = (particle.x.round(), particle.y.round());
+            if x < 0. || y < 0. {
+                continue;
+            }
+
+            let pixel = self.pixel(particle);
+            if let Some(cell) = buffer.get_mut(x as usize, y as usize) {
+                *cell = Pixel::new(pixel.value, pixel.color.over(&cell.color));
+            }
+        }
+    }
+}
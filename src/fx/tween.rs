@@ -0,0 +1,625 @@
+use std::f64::consts::PI;
+
+use crate::core::color::{Oklab, Rgb};
+
+/// A value that can be blended between two others
+pub trait Lerp: Clone {
+    /// Blend towards `other` by `t`, where `0.0` is this value and `1.0` is `other`.
+    /// `t` may go past either end with easing curves that overshoot
+    fn lerp(&self, other: &Self, t: f64) -> Self;
+}
+
+impl Lerp for f64 {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        self + (other - self) * t as f32
+    }
+}
+
+impl Lerp for (f64, f64) {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        (self.0.lerp(&other.0, t), self.1.lerp(&other.1, t))
+    }
+}
+
+/// Blends each channel directly, which can look dull half way between very
+/// different colors. Tween [`Oklab`] colors for even, perceptual blends
+impl Lerp for Rgb {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        Rgb::lerp(self, other, t)
+    }
+}
+
+impl Lerp for Oklab {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        Oklab::lerp(self, other, t)
+    }
+}
+
+/// Curves that shape how a tween moves from start to end
+///
+/// See <https://easings.net> for how each one looks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Ease {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    /// Pulls back before moving, overshooting the start
+    BackIn,
+    /// Overshoots the end before settling
+    BackOut,
+    BackInOut,
+    ElasticIn,
+    /// Springs past the end and wobbles back
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    /// Bounces against the end like a dropped ball
+    BounceOut,
+    BounceInOut,
+}
+
+impl Ease {
+    /// Map linear progress from `0.0` to `1.0` onto the curve
+    pub fn apply(&self, t: f64) -> f64 {
+        const BACK: f64 = 1.70158;
+        const BACK_IN_OUT: f64 = BACK * 1.525;
+
+        let t = t.clamp(0., 1.);
+        let in_out = |ease_in: fn(f64) -> f64| {
+            if t < 0.5 {
+                ease_in(t * 2.) / 2.
+            } else {
+                1. - ease_in((1. - t) * 2.) / 2.
+            }
+        };
+        let out = |ease_in: fn(f64) -> f64| 1. - ease_in(1. - t);
+
+        match self {
+            Ease::Linear => t,
+            Ease::QuadIn => t * t,
+            Ease::QuadOut => out(|t| t * t),
+            Ease::QuadInOut => in_out(|t| t * t),
+            Ease::CubicIn => t.powi(3),
+            Ease::CubicOut => out(|t| t.powi(3)),
+            Ease::CubicInOut => in_out(|t| t.powi(3)),
+            Ease::QuartIn => t.powi(4),
+            Ease::QuartOut => out(|t| t.powi(4)),
+            Ease::QuartInOut => in_out(|t| t.powi(4)),
+            Ease::SineIn => sine_in(t),
+            Ease::SineOut => out(sine_in),
+            Ease::SineInOut => in_out(sine_in),
+            Ease::ExpoIn => expo_in(t),
+            Ease::ExpoOut => out(expo_in),
+            Ease::ExpoInOut => in_out(expo_in),
+            Ease::BackIn => (BACK + 1.) * t.powi(3) - BACK * t * t,
+            Ease::BackOut => out(|t| (BACK + 1.) * t.powi(3) - BACK * t * t),
+            Ease::BackInOut => in_out(|t| (BACK_IN_OUT + 1.) * t.powi(3) - BACK_IN_OUT * t * t),
+            Ease::ElasticIn => elastic_in(t),
+            Ease::ElasticOut => out(elastic_in),
+            Ease::ElasticInOut => in_out(elastic_in),
+            Ease::BounceIn => out(bounce_out),
+            Ease::BounceOut => bounce_out(t),
+            Ease::BounceInOut => in_out(|t| 1. - bounce_out(1. - t)),
+        }
+    }
+}
+
+fn sine_in(t: f64) -> f64 {
+    1. - (t * PI / 2.).cos()
+}
+
+fn expo_in(t: f64) -> f64 {
+    if t <= 0. {
+        0.
+    } else {
+        2f64.powf(10. * t - 10.)
+    }
+}
+
+fn elastic_in(t: f64) -> f64 {
+    if t <= 0. || t >= 1. {
+        return t;
+    }
+    -(2f64.powf(10. * t - 10.)) * ((t * 10. - 10.75) * (2. * PI / 3.)).sin()
+}
+
+fn bounce_out(t: f64) -> f64 {
+    const N: f64 = 7.5625;
+    const D: f64 = 2.75;
+
+    if t < 1. / D {
+        N * t * t
+    } else if t < 2. / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
+
+/// How many times an animation plays
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Repeat {
+    #[default]
+    Once,
+    /// Play this many times in total, at least once
+    Times(usize),
+    Forever,
+}
+
+impl Repeat {
+    /// Whether another play follows the play numbered `cycle`, counting from zero
+    fn continues(&self, cycle: usize) -> bool {
+        match self {
+            Repeat::Once => false,
+            Repeat::Times(times) => cycle + 1 < *times,
+            Repeat::Forever => true,
+        }
+    }
+
+    /// Total number of plays, or `None` if it never stops
+    fn plays(&self) -> Option<f64> {
+        match self {
+            Repeat::Once => Some(1.),
+            Repeat::Times(times) => Some((*times).max(1) as f64),
+            Repeat::Forever => None,
+        }
+    }
+}
+
+/// Something that plays out over time, such as a [`Tween`] or a group of them
+pub trait Animation {
+    /// Advance by `delta` seconds, returning the seconds left over if the animation
+    /// finished part way through
+    fn update(&mut self, delta: f64) -> f64;
+
+    fn is_finished(&self) -> bool;
+
+    /// Go back to the start, ready to play again
+    fn reset(&mut self);
+
+    /// Seconds taken to play through, or `None` if the animation repeats forever
+    fn duration(&self) -> Option<f64>;
+}
+
+/// Moves a value from one point to another over time
+///
+/// # Example
+/// ```
+/// use clige::fx::{Animation, Ease, Tween};
+///
+/// let mut x = Tween::new(0., 10., 2.).ease(Ease::QuadIn);
+/// x.update(1.);
+/// assert_eq!(x.value(), 2.5);
+///
+/// x.update(1.);
+/// assert_eq!(x.value(), 10.);
+/// assert!(x.is_finished());
+/// ```
+pub struct Tween<T: Lerp> {
+    from: T,
+    to: T,
+    duration: f64,
+    delay: f64,
+    ease: Ease,
+    repeat: Repeat,
+    yoyo: bool,
+    waited: f64,
+    elapsed: f64,
+    cycle: usize,
+    finished: bool,
+    on_update: Option<Box<dyn FnMut(T)>>,
+    on_loop: Option<Box<dyn FnMut(usize)>>,
+    on_complete: Option<Box<dyn FnMut()>>,
+}
+
+impl<T: Lerp> Tween<T> {
+    /// Tween from `from` to `to` over `duration` seconds
+    pub fn new(from: T, to: T, duration: f64) -> Self {
+        Tween {
+            from,
+            to,
+            duration: duration.max(0.),
+            delay: 0.,
+            ease: Ease::Linear,
+            repeat: Repeat::Once,
+            yoyo: false,
+            waited: 0.,
+            elapsed: 0.,
+            cycle: 0,
+            finished: false,
+            on_update: None,
+            on_loop: None,
+            on_complete: None,
+        }
+    }
+
+    pub fn ease(self, ease: Ease) -> Self {
+        Tween { ease, ..self }
+    }
+
+    /// Seconds to wait before the first play starts
+    pub fn delay(self, delay: f64) -> Self {
+        Tween {
+            delay: delay.max(0.),
+            ..self
+        }
+    }
+
+    pub fn repeat(self, repeat: Repeat) -> Self {
+        Tween { repeat, ..self }
+    }
+
+    /// Play every other repeat backwards, going back and forth between the ends
+    pub fn yoyo(self, yoyo: bool) -> Self {
+        Tween { yoyo, ..self }
+    }
+
+    /// Called with the value each time the tween is updated
+    ///
+    /// Used to pass values out of tweens that are inside of a [`Sequence`] or [`Parallel`].
+    pub fn on_update<F: FnMut(T) + 'static>(self, callback: F) -> Self {
+        Tween {
+            on_update: Some(Box::new(callback)),
+            ..self
+        }
+    }
+
+    /// Called with the number of the play that is starting each time the tween repeats
+    pub fn on_loop<F: FnMut(usize) + 'static>(self, callback: F) -> Self {
+        Tween {
+            on_loop: Some(Box::new(callback)),
+            ..self
+        }
+    }
+
+    /// Called once the last play finishes
+    pub fn on_complete<F: FnMut() + 'static>(self, callback: F) -> Self {
+        Tween {
+            on_complete: Some(Box::new(callback)),
+            ..self
+        }
+    }
+
+    /// How far through the current play the tween is, from `0.0` to `1.0` before easing
+    pub fn progress(&self) -> f64 {
+        if self.duration <= 0. {
+            return 1.;
+        }
+        self.elapsed / self.duration
+    }
+
+    /// The number of the current play, counting from zero
+    pub fn cycle(&self) -> usize {
+        self.cycle
+    }
+
+    pub fn value(&self) -> T {
+        let mut progress = self.progress();
+        if self.yoyo && self.cycle % 2 == 1 {
+            progress = 1. - progress;
+        }
+        self.from.lerp(&self.to, self.ease.apply(progress))
+    }
+
+    fn advance(&mut self, delta: f64) -> f64 {
+        let mut time = delta;
+        let wait = (self.delay - self.waited).min(time);
+        self.waited += wait;
+        time -= wait;
+        if self.waited < self.delay {
+            return 0.;
+        }
+
+        loop {
+            let remaining = self.duration - self.elapsed;
+            if time < remaining {
+                self.elapsed += time;
+                return 0.;
+            }
+            time -= remaining;
+            self.elapsed = self.duration;
+
+            // Plays that take no time would repeat forever without using any up
+            if !self.repeat.continues(self.cycle) || self.duration <= 0. {
+                self.finished = self.repeat != Repeat::Forever;
+                return if self.finished { time } else { 0. };
+            }
+
+            self.cycle += 1;
+            self.elapsed = 0.;
+            if let Some(callback) = self.on_loop.as_mut() {
+                callback(self.cycle);
+            }
+        }
+    }
+}
+
+impl<T: Lerp> Animation for Tween<T> {
+    fn update(&mut self, delta: f64) -> f64 {
+        if self.finished {
+            return delta;
+        }
+
+        let left = self.advance(delta);
+        if self.on_update.is_some() {
+            let value = self.value();
+            if let Some(callback) = self.on_update.as_mut() {
+                callback(value);
+            }
+        }
+        if self.finished {
+            if let Some(callback) = self.on_complete.as_mut() {
+                callback();
+            }
+        }
+        left
+    }
+
+    fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn reset(&mut self) {
+        self.waited = 0.;
+        self.elapsed = 0.;
+        self.cycle = 0;
+        self.finished = false;
+    }
+
+    fn duration(&self) -> Option<f64> {
+        Some(self.delay + self.duration * self.repeat.plays()?)
+    }
+}
+
+/// Does nothing for a while, for pauses inside of a [`Sequence`]
+struct Wait {
+    duration: f64,
+    elapsed: f64,
+}
+
+impl Animation for Wait {
+    fn update(&mut self, delta: f64) -> f64 {
+        let step = (self.duration - self.elapsed).min(delta);
+        self.elapsed += step;
+        delta - step
+    }
+
+    fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    fn reset(&mut self) {
+        self.elapsed = 0.;
+    }
+
+    fn duration(&self) -> Option<f64> {
+        Some(self.duration)
+    }
+}
+
+/// Plays animations one after another
+///
+/// # Example
+/// ```
+/// use std::{cell::Cell, rc::Rc};
+/// use clige::fx::{Animation, Sequence, Tween};
+///
+/// let x = Rc::new(Cell::new(0.));
+/// let (a, b) = (x.clone(), x.clone());
+/// let mut path = Sequence::new()
+///     .then(Tween::new(0., 10., 1.).on_update(move |v| a.set(v)))
+///     .wait(0.5)
+///     .then(Tween::new(10., 0., 1.).on_update(move |v| b.set(v)));
+///
+/// path.update(2.);
+/// assert_eq!(x.get(), 5.);
+/// ```
+#[derive(Default)]
+pub struct Sequence {
+    animations: Vec<Box<dyn Animation>>,
+    index: usize,
+    repeat: Repeat,
+    cycle: usize,
+    finished: bool,
+    on_complete: Option<Box<dyn FnMut()>>,
+}
+
+impl Sequence {
+    pub fn new() -> Self {
+        Sequence::default()
+    }
+
+    /// Play an animation after the ones before it
+    pub fn then<A: Animation + 'static>(mut self, animation: A) -> Self {
+        self.animations.push(Box::new(animation));
+        self
+    }
+
+    /// Pause for `seconds` before the next animation
+    pub fn wait(self, seconds: f64) -> Self {
+        self.then(Wait {
+            duration: seconds.max(0.),
+            elapsed: 0.,
+        })
+    }
+
+    pub fn repeat(self, repeat: Repeat) -> Self {
+        Sequence { repeat, ..self }
+    }
+
+    /// Called once the last play finishes
+    pub fn on_complete<F: FnMut() + 'static>(self, callback: F) -> Self {
+        Sequence {
+            on_complete: Some(Box::new(callback)),
+            ..self
+        }
+    }
+
+    /// Index of the animation that is playing
+    pub fn current(&self) -> usize {
+        self.index
+    }
+}
+
+impl Animation for Sequence {
+    fn update(&mut self, delta: f64) -> f64 {
+        let mut time = delta;
+        let mut started = time;
+        while !self.finished {
+            if let Some(animation) = self.animations.get_mut(self.index) {
+                time = animation.update(time);
+                if !animation.is_finished() {
+                    return 0.;
+                }
+                self.index += 1;
+                continue;
+            }
+
+            // Plays that take no time would repeat forever without using any up
+            if self.repeat.continues(self.cycle) && time < started {
+                self.cycle += 1;
+                self.index = 0;
+                started = time;
+                self.animations.iter_mut().for_each(|a| a.reset());
+            } else if self.repeat == Repeat::Forever {
+                return 0.;
+            } else {
+                self.finished = true;
+                if let Some(callback) = self.on_complete.as_mut() {
+                    callback();
+                }
+            }
+        }
+        time
+    }
+
+    fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn reset(&mut self) {
+        self.index = 0;
+        self.cycle = 0;
+        self.finished = false;
+        self.animations.iter_mut().for_each(|a| a.reset());
+    }
+
+    fn duration(&self) -> Option<f64> {
+        let play = self
+            .animations
+            .iter()
+            .map(|a| a.duration())
+            .sum::<Option<f64>>()?;
+        Some(play * self.repeat.plays()?)
+    }
+}
+
+/// Plays animations at the same time, finishing once they all have
+#[derive(Default)]
+pub struct Parallel {
+    animations: Vec<Box<dyn Animation>>,
+    repeat: Repeat,
+    cycle: usize,
+    finished: bool,
+    on_complete: Option<Box<dyn FnMut()>>,
+}
+
+impl Parallel {
+    pub fn new() -> Self {
+        Parallel::default()
+    }
+
+    /// Play an animation alongside the others
+    pub fn with<A: Animation + 'static>(mut self, animation: A) -> Self {
+        self.animations.push(Box::new(animation));
+        self
+    }
+
+    pub fn repeat(self, repeat: Repeat) -> Self {
+        Parallel { repeat, ..self }
+    }
+
+    /// Called once the last play finishes
+    pub fn on_complete<F: FnMut() + 'static>(self, callback: F) -> Self {
+        Parallel {
+            on_complete: Some(Box::new(callback)),
+            ..self
+        }
+    }
+}
+
+impl Animation for Parallel {
+    fn update(&mut self, delta: f64) -> f64 {
+        let mut time = delta;
+        while !self.finished {
+            // Time left after the slowest animation finished
+            let left = self
+                .animations
+                .iter_mut()
+                .filter(|a| !a.is_finished())
+                .map(|a| a.update(time))
+                .fold(time, f64::min);
+            if self.animations.iter().any(|a| !a.is_finished()) {
+                return 0.;
+            }
+
+            if self.repeat.continues(self.cycle) && left < time {
+                self.cycle += 1;
+                self.animations.iter_mut().for_each(|a| a.reset());
+            } else if self.repeat == Repeat::Forever {
+                return 0.;
+            } else {
+                self.finished = true;
+                if let Some(callback) = self.on_complete.as_mut() {
+                    callback();
+                }
+            }
+            time = left;
+        }
+        time
+    }
+
+    fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn reset(&mut self) {
+        self.cycle = 0;
+        self.finished = false;
+        self.animations.iter_mut().for_each(|a| a.reset());
+    }
+
+    fn duration(&self) -> Option<f64> {
+        let play = self
+            .animations
+            .iter()
+            .map(|a| a.duration())
+            .try_fold(0., |longest: f64, duration| Some(longest.max(duration?)))?;
+        Some(play * self.repeat.plays()?)
+    }
+}
@@ -0,0 +1,186 @@
+use std::{fmt::Display, str::FromStr};
+
+use rand::Rng;
+
+/// A dice expression such as `3d6+2`, written in the usual tabletop notation
+///
+/// Expressions are sums of dice and numbers, like `d20`, `2d8-1`, or `1d6+1d4+3`.
+/// The count before `d` defaults to one, and any term can be subtracted. An expression
+/// holds at most [`Dice::MAX_COUNT`] dice, and totals saturate rather than overflow.
+///
+/// # Example
+/// ```
+/// use clige::gen::{Dice, Random};
+///
+/// let damage: Dice = "3d6+2".parse().unwrap();
+/// assert_eq!((damage.min(), damage.max()), (5, 20));
+///
+/// let mut rng = Random::from(7);
+/// let roll = rng.roll(&damage);
+/// assert!((5..=20).contains(&roll));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Dice {
+    /// Groups of dice as `(count, sides)`, where a negative count subtracts the group
+    groups: Vec<(i64, u32)>,
+    modifier: i64,
+}
+
+impl Dice {
+    /// Most dice an expression can roll, so rolling stays quick and totals can't overflow
+    pub const MAX_COUNT: u32 = 1000;
+
+    /// `count` dice with `sides` sides each, plus `modifier`, with at most
+    /// [`Dice::MAX_COUNT`] dice
+    pub fn new(count: u32, sides: u32, modifier: i64) -> Self {
+        Dice {
+            groups: vec![(count.min(Self::MAX_COUNT) as i64, sides.max(1))],
+            modifier,
+        }
+    }
+
+    /// Lowest total that can be rolled
+    pub fn min(&self) -> i64 {
+        self.groups
+            .iter()
+            .map(|(count, sides)| {
+                if *count < 0 {
+                    count * *sides as i64
+                } else {
+                    *count
+                }
+            })
+            .sum::<i64>()
+            .saturating_add(self.modifier)
+    }
+
+    /// Highest total that can be rolled
+    pub fn max(&self) -> i64 {
+        self.groups
+            .iter()
+            .map(|(count, sides)| {
+                if *count < 0 {
+                    *count
+                } else {
+                    count * *sides as i64
+                }
+            })
+            .sum::<i64>()
+            .saturating_add(self.modifier)
+    }
+
+    /// Average total over many rolls
+    pub fn mean(&self) -> f64 {
+        self.groups
+            .iter()
+            .map(|(count, sides)| *count as f64 * (*sides as f64 + 1.) / 2.)
+            .sum::<f64>()
+            + self.modifier as f64
+    }
+
+    /// Roll every die and add up the total
+    pub fn roll<R: Rng + ?Sized>(&self, rng: &mut R) -> i64 {
+        self.groups
+            .iter()
+            .map(|(count, sides)| {
+                let total: i64 = (0..count.unsigned_abs())
+                    .map(|_| rng.gen_range(1..=*sides) as i64)
+                    .sum();
+                total * count.signum()
+            })
+            .sum::<i64>()
+            .saturating_add(self.modifier)
+    }
+}
+
+impl FromStr for Dice {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = |reason: &str| format!("Invalid dice expression {:?}: {}", value, reason);
+        let expression: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+        if expression.is_empty() {
+            return Err(error("expected dice like 3d6+2"));
+        }
+
+        let mut dice = Dice::default();
+        let mut total = 0;
+        let mut rest = expression.as_str();
+        while !rest.is_empty() {
+            let negative = rest.starts_with('-');
+            let sign = if negative { -1 } else { 1 };
+            let start = usize::from(rest.starts_with(['+', '-']));
+
+            let end = rest[start..]
+                .find(['+', '-'])
+                .map_or(rest.len(), |end| start + end);
+            let (signed, next) = rest.split_at(end);
+            let term = &signed[start..];
+            rest = next;
+            if term.is_empty() {
+                return Err(error("expected a term after + or -"));
+            }
+
+            match term.to_ascii_lowercase().split_once('d') {
+                Some((count, sides)) => {
+                    let count = match count {
+                        "" => 1,
+                        count => count
+                            .parse::<u32>()
+                            .map_err(|_| error("invalid dice count"))?,
+                    };
+                    let sides = sides
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|sides| *sides > 0)
+                        .ok_or_else(|| error("invalid number of sides"))?;
+                    total = count
+                        .checked_add(total)
+                        .filter(|total| *total <= Dice::MAX_COUNT)
+                        .ok_or_else(|| error("too many dice"))?;
+                    dice.groups.push((sign * count as i64, sides));
+                }
+                None => {
+                    // Parsed with its sign, so the most negative number still fits
+                    let number = match negative {
+                        true => signed.parse::<i64>(),
+                        false => term.parse::<i64>(),
+                    }
+                    .map_err(|_| error("invalid number"))?;
+                    dice.modifier = dice
+                        .modifier
+                        .checked_add(number)
+                        .ok_or_else(|| error("number is too large"))?;
+                }
+            }
+        }
+        Ok(dice)
+    }
+}
+
+impl TryFrom<&str> for Dice {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for Dice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (count, sides)) in self.groups.iter().enumerate() {
+            if *count < 0 {
+                write!(f, "-")?;
+            } else if i > 0 {
+                write!(f, "+")?;
+            }
+            write!(f, "{}d{}", count.unsigned_abs(), sides)?;
+        }
+
+        match self.modifier {
+            0 if !self.groups.is_empty() => Ok(()),
+            modifier if modifier < 0 || self.groups.is_empty() => write!(f, "{}", modifier),
+            modifier => write!(f, "+{}", modifier),
+        }
+    }
+}
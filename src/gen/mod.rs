@@ -1,6 +1,7 @@
+mod dice;
 mod noise_gen;
 mod random;
 
+pub use dice::Dice;
 pub use noise_gen::NoiseMap;
 pub use random::Random;
-
@@ -1,4 +1,15 @@
-use noise::{Billow, Fbm, NoiseFn, Perlin, Simplex, Worley};
+use std::thread;
+
+use noise::{Billow, Fbm, NoiseFn, Perlin, Seedable, Simplex, Worley};
+
+use super::Random;
+use crate::{
+    core::{
+        buffer::{Buffer, PixelBuffer},
+        data::{Pixel, Rect},
+    },
+    grid::Grid,
+};
 
 pub struct NoiseMapBuilder<N>
 where
@@ -79,6 +90,34 @@ where
     y_bounds: (f64, f64),
 }
 
+impl<N> NoiseMap<N>
+where
+    N: NoiseFn<f64, 2> + Seedable + Default,
+{
+    /// Noise seeded from a generator, using [`Random::noise_seed`]
+    ///
+    /// Unlike [`NoiseMap::perlin`] and the other constructors, which only keep the low
+    /// 32 bits of a seed, every bit of the generator's state changes the noise. Pair it
+    /// with [`Random::fork`] to give each kind of noise its own seed.
+    ///
+    /// # Example
+    /// ```
+    /// use clige::gen::{NoiseMap, Random};
+    /// use noise::Perlin;
+    ///
+    /// let world = Random::from("Some Seed goes here 2109@");
+    /// let height = NoiseMap::<Perlin>::from_random(&world.fork("height"))
+    ///     .step(0.15, 0.15)
+    ///     .build();
+    /// let rain = NoiseMap::<Perlin>::from_random(&world.fork("rain")).build();
+    ///
+    /// assert_ne!(height.get(3, 7), rain.get(3, 7));
+    /// ```
+    pub fn from_random(random: &Random) -> NoiseMapBuilder<N> {
+        NoiseMapBuilder::new(N::default().set_seed(random.noise_seed()))
+    }
+}
+
 impl NoiseMap<Perlin> {
     pub fn perlin(seed: i64) -> NoiseMapBuilder<Perlin> {
         NoiseMapBuilder::new(Perlin::new(seed as u32))
@@ -149,3 +188,112 @@ where
         self.noise.get(pad_array(&[current_x, current_y]))
     }
 }
+
+impl<N> NoiseMap<N>
+where
+    N: NoiseFn<f64, 2>,
+{
+    /// Sample every position in `area` into a grid, with the top left of the area at
+    /// `(0, 0)` of the grid
+    ///
+    /// # Example
+    /// ```
+    /// use clige::core::data::Rect;
+    /// use clige::gen::NoiseMap;
+    ///
+    /// let noise = NoiseMap::perlin(7).step(0.1, 0.1).build();
+    /// let heights = noise.fill(Rect::from([10, 20, 50, 30]));
+    ///
+    /// assert_eq!((heights.width(), heights.height()), (40, 10));
+    /// assert_eq!(heights[(5, 2)], noise.get(15, 22));
+    /// ```
+    pub fn fill(&self, area: Rect) -> Grid<f64> {
+        Grid::from_fn(area.width(), area.height(), |x, y| {
+            self.get(area.left + x, area.top + y)
+        })
+    }
+
+    /// Sample every position in `area` and draw it into the top left of the buffer,
+    /// turning each sample into a pixel with `pixel`
+    ///
+    /// Samples that would land outside of the buffer are skipped.
+    pub fn fill_buffer<F>(&self, buffer: &mut PixelBuffer, area: Rect, mut pixel: F)
+    where
+        F: FnMut(f64) -> Pixel,
+    {
+        let (width, height) = (
+            area.width().min(buffer.width()),
+            area.height().min(buffer.height()),
+        );
+        let stride = buffer.width();
+        for y in 0..height {
+            for x in 0..width {
+                buffer.pixels[y * stride + x] = pixel(self.get(area.left + x, area.top + y));
+            }
+        }
+    }
+}
+
+impl<N> NoiseMap<N>
+where
+    N: NoiseFn<f64, 2> + Sync,
+{
+    /// [`NoiseMap::fill`], splitting the rows between `threads` threads
+    pub fn fill_parallel(&self, area: Rect, threads: usize) -> Grid<f64> {
+        let (width, height) = (area.width(), area.height());
+        let mut cells = vec![0.; width * height];
+        rows(&mut cells, width, height, threads, |y, row| {
+            for (x, cell) in row.iter_mut().enumerate() {
+                *cell = self.get(area.left + x, area.top + y);
+            }
+        });
+        Grid::from_vec(width, height, cells).expect("cells fill the area")
+    }
+
+    /// [`NoiseMap::fill_buffer`], splitting the rows between `threads` threads
+    pub fn fill_buffer_parallel<F>(
+        &self,
+        buffer: &mut PixelBuffer,
+        area: Rect,
+        threads: usize,
+        pixel: F,
+    ) where
+        F: Fn(f64) -> Pixel + Sync,
+    {
+        let (width, height) = (
+            area.width().min(buffer.width()),
+            area.height().min(buffer.height()),
+        );
+        let stride = buffer.width();
+        rows(&mut buffer.pixels, stride, height, threads, |y, row| {
+            for (x, cell) in row[..width].iter_mut().enumerate() {
+                *cell = pixel(self.get(area.left + x, area.top + y));
+            }
+        });
+    }
+}
+
+/// Run `f` on each of the first `height` rows of `cells`, which are `stride` long,
+/// spreading the rows evenly between up to `threads` threads
+fn rows<T, F>(cells: &mut [T], stride: usize, height: usize, threads: usize, f: F)
+where
+    T: Send,
+    F: Fn(usize, &mut [T]) + Sync,
+{
+    if stride == 0 || height == 0 {
+        return;
+    }
+
+    let per_thread = height.div_ceil(threads.clamp(1, height));
+    let cells = &mut cells[..stride * height];
+    thread::scope(|scope| {
+        for (chunk, block) in cells.chunks_mut(stride * per_thread).enumerate() {
+            let f = &f;
+            scope.spawn(move || {
+                for (i, row) in block.chunks_mut(stride).enumerate() {
+                    f(chunk * per_thread + i, row);
+                }
+            });
+        }
+    });
+}
@@ -1,23 +1,195 @@
-use std::{
-    collections::hash_map::DefaultHasher,
-    hash::{Hash, Hasher},
-};
+use std::f64::consts::TAU;
 
-use rand::{SeedableRng, Rng};
+use rand::{
+    seq::{index, SliceRandom},
+    Rng, SeedableRng,
+};
 use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use super::Dice;
 
 /// Struct for generating platform independant random numbers
 /// 
 /// The generators can be created from seeds of strings or i64
-pub struct Random(i64, ChaCha8Rng);
+///
+/// A seed always gives the same numbers, on any platform and with any Rust version:
+/// - An `i64` seed, or a string that parses as one, is used as is.
+/// - Any other string is hashed into a seed with 64 bit FNV-1a over its UTF-8 bytes.
+///
+/// The seed is then expanded into the ChaCha8 key with `SeedableRng::seed_from_u64`.
+///
+/// Serializing a generator saves its exact position, so a loaded generator carries on
+/// with the same numbers the original would have produced.
+///
+/// # Example
+/// ```
+/// use clige::gen::Random;
+/// use rand::Rng;
+///
+/// let mut rng = Random::from(42);
+/// rng.generator().gen::<u64>();
+///
+/// let saved = serde_json::to_string(&rng).unwrap();
+/// let mut resumed: Random = serde_json::from_str(&saved).unwrap();
+///
+/// assert_eq!(resumed.generator().gen::<u64>(), rng.generator().gen::<u64>());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Random {
+    seed: i64,
+    rng: ChaCha8Rng,
+}
 
 impl<'rng> Random {
     pub fn seed(&self) -> i64 {
-        self.0
+        self.seed
     }
 
     pub fn generator(&'rng mut self) -> &'rng mut ChaCha8Rng {
-        &mut self.1
+        &mut self.rng
+    }
+
+    /// How far through its stream the generator is, counted in 32 bit words
+    pub fn word_pos(&self) -> u128 {
+        self.rng.get_word_pos()
+    }
+
+    /// Jump to a position in the stream, as returned by [`Random::word_pos`]
+    pub fn set_word_pos(&mut self, word_pos: u128) {
+        self.rng.set_word_pos(word_pos);
+    }
+
+    /// Which of the independent streams of numbers for the seed is being read
+    pub fn stream(&self) -> u64 {
+        self.rng.get_stream()
+    }
+
+    /// Switch to another stream for the same seed, keeping the position
+    pub fn set_stream(&mut self, stream: u64) {
+        self.rng.set_stream(stream);
+    }
+
+    /// An independent generator for one part of the game, such as `"terrain"` or `"loot"`
+    ///
+    /// Forks use their own ChaCha stream, picked from the parent's key and stream and
+    /// the name. They don't depend on how many numbers the parent has produced, so
+    /// drawing more numbers in one system never changes what another system sees.
+    ///
+    /// # Example
+    /// ```
+    /// use clige::gen::Random;
+    /// use rand::Rng;
+    ///
+    /// let mut world = Random::from(1234);
+    /// let first = world.fork("terrain").generator().gen::<u64>();
+    ///
+    /// world.generator().gen::<u64>();
+    /// assert_eq!(world.fork("terrain").generator().gen::<u64>(), first);
+    /// assert_ne!(world.fork("loot").generator().gen::<u64>(), first);
+    /// ```
+    pub fn fork(&self, name: &str) -> Random {
+        self.child(hash(name.as_bytes()))
+    }
+
+    /// An independent generator for a position, such as a chunk of the world, so
+    /// each chunk is generated the same way whichever order they are visited in
+    pub fn fork_at(&self, x: i64, y: i64) -> Random {
+        self.child(mix(mix(x as u64) ^ (y as u64).rotate_left(32)))
+    }
+
+    fn child(&self, id: u64) -> Random {
+        let stream = mix(self.rng.get_stream() ^ mix(id));
+        let mut rng = ChaCha8Rng::from_seed(self.rng.get_seed());
+        rng.set_stream(stream);
+        Random {
+            seed: mix(self.seed as u64 ^ stream) as i64,
+            rng,
+        }
+    }
+
+    /// A seed for noise functions, which only take 32 bits, made from the whole state
+    /// of the generator rather than cutting [`Random::seed`] down to size
+    ///
+    /// Like a fork, it doesn't change as numbers are drawn from the generator.
+    pub fn noise_seed(&self) -> u32 {
+        self.fork("noise").rng.gen()
+    }
+
+    /// `true` with a probability of `probability`, from `0.0` for never to `1.0` for always
+    pub fn chance(&mut self, probability: f64) -> bool {
+        self.rng.gen::<f64>() < probability
+    }
+
+    /// A random item from a slice, or `None` if it is empty
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        items.choose(&mut self.rng)
+    }
+
+    /// A random item from a table of `(item, weight)`, where items with twice the
+    /// weight are picked twice as often
+    ///
+    /// Weights of zero or less are never picked. Returns `None` if nothing can be.
+    ///
+    /// # Example
+    /// ```
+    /// use clige::gen::Random;
+    ///
+    /// let loot = [("gold", 10.), ("potion", 5.), ("sword", 1.), ("nothing", 0.)];
+    /// let mut rng = Random::from(3);
+    /// assert_ne!(rng.weighted(&loot), Some(&"nothing"));
+    /// ```
+    pub fn weighted<'a, T>(&mut self, table: &'a [(T, f64)]) -> Option<&'a T> {
+        let total: f64 = table.iter().map(|(_, weight)| weight.max(0.)).sum();
+        if total <= 0. || !total.is_finite() {
+            return None;
+        }
+
+        let mut target = self.rng.gen_range(0.0..total);
+        let mut last = None;
+        for (item, weight) in table.iter().filter(|(_, weight)| *weight > 0.) {
+            if target < *weight {
+                return Some(item);
+            }
+            target -= weight;
+            last = Some(item);
+        }
+        // Rounding can leave the target just past the final weight
+        last
+    }
+
+    /// Put the items of a slice in a random order
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        items.shuffle(&mut self.rng);
+    }
+
+    /// Up to `count` different items from a slice in a random order, without picking
+    /// any item twice
+    pub fn sample<'a, T>(&mut self, items: &'a [T], count: usize) -> Vec<&'a T> {
+        index::sample(&mut self.rng, items.len(), count.min(items.len()))
+            .into_iter()
+            .map(|i| &items[i])
+            .collect()
+    }
+
+    /// Roll dice, such as a [`Dice`] parsed from `3d6+2`
+    pub fn roll(&mut self, dice: &Dice) -> i64 {
+        dice.roll(&mut self.rng)
+    }
+
+    /// A number from a normal distribution, the bell curve around `mean` where about
+    /// two thirds of numbers fall within `deviation` of it
+    pub fn normal(&mut self, mean: f64, deviation: f64) -> f64 {
+        // Box-Muller transform, keeping the first value of the pair
+        let u = 1. - self.rng.gen::<f64>();
+        let v = self.rng.gen::<f64>();
+        mean + deviation * (-2. * u.ln()).sqrt() * (TAU * v).cos()
+    }
+
+    /// A number from an exponential distribution, such as the time between events
+    /// that happen `rate` times per unit on average
+    pub fn exponential(&mut self, rate: f64) -> f64 {
+        -(1. - self.rng.gen::<f64>()).ln() / rate
     }
 
     pub fn new() -> Self {
@@ -25,23 +197,32 @@ impl<'rng> Random {
         let seed = rng.get_seed();
         let index = rng.gen_range(0..4);
 
-        let seed = u64::from_ne_bytes(seed[(0 + 8*index)..(8 + 8*index)].try_into().unwrap());
-        Random(seed as i64, ChaCha8Rng::seed_from_u64(seed))
+        let seed = u64::from_ne_bytes(seed[(8 * index)..(8 + 8 * index)].try_into().unwrap());
+        Random {
+            seed: seed as i64,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Default for Random {
+    fn default() -> Self {
+        Random::new()
     }
 }
 
 impl From<&str> for Random {
+    /// Seed from a string, which is parsed as an `i64` or hashed with 64 bit FNV-1a
     fn from(value: &str) -> Self {
         let seed = match value.parse::<i64>() {
             Ok(s) => s as u64,
-            Err(_) => {
-                let mut hasher = DefaultHasher::new();
-                value.hash(&mut hasher);
-                hasher.finish()
-            }
+            Err(_) => hash(value.as_bytes()),
         };
 
-        Random(seed as i64, ChaCha8Rng::seed_from_u64(seed))
+        Random {
+            seed: seed as i64,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
     }
 }
 
@@ -53,6 +234,24 @@ impl From<String> for Random {
 
 impl From<i64> for Random {
     fn from(value: i64) -> Self {
-        Random(value, ChaCha8Rng::seed_from_u64(value as u64))
+        Random {
+            seed: value,
+            rng: ChaCha8Rng::seed_from_u64(value as u64),
+        }
     }
 }
+
+/// FNV-1a hash of some bytes, which gives the same value on every platform and
+/// Rust version
+fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// SplitMix64 finalizer, spreading every bit of the input across the output
+fn mix(mut value: u64) -> u64 {
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    value ^ (value >> 31)
+}
@@ -0,0 +1,331 @@
+use super::Grid;
+use crate::core::{
+    buffer::{Buffer, PixelBuffer},
+    color::Color,
+    data::Pixel,
+};
+
+/// How [`fov`] decides which cells can be seen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Algorithm {
+    /// Recursive shadowcasting. Fast, with the familiar diamond shaped shadows
+    /// behind pillars
+    #[default]
+    Shadowcasting,
+    /// A cell is visible when a clear line runs from any part of the viewer's cell
+    /// to any part of it. Shows more around corners and pillars than shadowcasting
+    Permissive,
+}
+
+/// Cells visible from `origin` within `radius` cells
+///
+/// A radius larger than the grid is the same as seeing the whole grid.
+///
+/// `opacity` holds `true` for cells that block sight. Opaque cells that are lit,
+/// such as the walls of a room, are visible themselves.
+///
+/// # Example
+/// ```
+/// use clige::grid::{fov::{fov, Algorithm}, Grid};
+///
+/// // A wall across x = 5 with a gap at y = 3
+/// let opacity = Grid::from_fn(10, 7, |x, y| x == 5 && y != 3);
+/// let visible = fov(&opacity, (2, 3), 8, Algorithm::Shadowcasting);
+///
+/// assert!(visible[(5, 0)]);
+/// assert!(visible[(8, 3)]);
+/// assert!(!visible[(8, 0)]);
+/// ```
+pub fn fov(
+    opacity: &Grid<bool>,
+    origin: (usize, usize),
+    radius: usize,
+    algorithm: Algorithm,
+) -> Grid<bool> {
+    match algorithm {
+        Algorithm::Shadowcasting => shadowcast(opacity, origin, radius),
+        Algorithm::Permissive => permissive(opacity, origin, radius),
+    }
+}
+
+fn in_radius(dx: isize, dy: isize, radius: usize) -> bool {
+    (dx * dx + dy * dy) as usize <= radius * radius + radius
+}
+
+/// Field of view with recursive shadowcasting
+pub fn shadowcast(opacity: &Grid<bool>, origin: (usize, usize), radius: usize) -> Grid<bool> {
+    let radius = radius.min(opacity.width() + opacity.height());
+    let mut visible = Grid::new(opacity.width(), opacity.height(), false);
+    if opacity.get(origin.0, origin.1).is_none() {
+        return visible;
+    }
+    visible[origin] = true;
+
+    // Transforms from octant coordinates to grid offsets
+    const OCTANTS: [(isize, isize, isize, isize); 8] = [
+        (1, 0, 0, 1),
+        (0, 1, 1, 0),
+        (0, -1, 1, 0),
+        (-1, 0, 0, 1),
+        (-1, 0, 0, -1),
+        (0, -1, -1, 0),
+        (0, 1, -1, 0),
+        (1, 0, 0, -1),
+    ];
+    for octant in OCTANTS {
+        cast(opacity, &mut visible, origin, radius, 1, 1.0, 0.0, octant);
+    }
+    visible
+}
+
+/// Scan one octant a row at a time, starting at `row`, between the `start` and `end` slopes
+#[allow(clippy::too_many_arguments)]
+fn cast(
+    opacity: &Grid<bool>,
+    visible: &mut Grid<bool>,
+    origin: (usize, usize),
+    radius: usize,
+    row: usize,
+    mut start: f64,
+    end: f64,
+    (xx, xy, yx, yy): (isize, isize, isize, isize),
+) {
+    if start < end {
+        return;
+    }
+
+    let (ox, oy) = (origin.0 as isize, origin.1 as isize);
+    for distance in row..=radius {
+        let mut blocked = false;
+        let mut next_start = start;
+        let dy = -(distance as isize);
+
+        for dx in -(distance as isize)..=0 {
+            let left = (dx as f64 - 0.5) / (dy as f64 + 0.5);
+            let right = (dx as f64 + 0.5) / (dy as f64 - 0.5);
+            if start < right {
+                continue;
+            }
+            if end > left {
+                break;
+            }
+
+            let x = ox + dx * xx + dy * xy;
+            let y = oy + dx * yx + dy * yy;
+            let inside = opacity.in_bounds(x, y);
+            if inside && in_radius(dx, dy, radius) {
+                visible[(x as usize, y as usize)] = true;
+            }
+
+            // Cells past the edge of the grid block sight
+            let opaque = !inside || opacity[(x as usize, y as usize)];
+            if blocked {
+                if opaque {
+                    next_start = right;
+                } else {
+                    blocked = false;
+                    start = next_start;
+                }
+            } else if opaque && distance < radius {
+                blocked = true;
+                cast(
+                    opacity,
+                    visible,
+                    origin,
+                    radius,
+                    distance + 1,
+                    start,
+                    left,
+                    (xx, xy, yx, yy),
+                );
+                next_start = right;
+            }
+        }
+
+        if blocked {
+            break;
+        }
+    }
+}
+
+/// Field of view where a cell is visible if a clear line runs between any part of
+/// the viewer's cell and any part of it
+///
+/// Lines are tested between the centers and corners of both cells, which makes this
+/// slower than [`shadowcast`] for large radii. Lines may graze the edges and corners
+/// of opaque cells without being blocked, but can't slip between two opaque cells
+/// that share an edge.
+pub fn permissive(opacity: &Grid<bool>, origin: (usize, usize), radius: usize) -> Grid<bool> {
+    let radius = radius.min(opacity.width() + opacity.height());
+    let mut visible = Grid::new(opacity.width(), opacity.height(), false);
+    if opacity.get(origin.0, origin.1).is_none() {
+        return visible;
+    }
+
+    const POINTS: [(f64, f64); 5] = [(0.5, 0.5), (0., 0.), (1., 0.), (0., 1.), (1., 1.)];
+
+    let (ox, oy) = (origin.0 as isize, origin.1 as isize);
+    let r = radius as isize;
+    for y in (oy - r).max(0)..=(oy + r).min(opacity.height() as isize - 1) {
+        for x in (ox - r).max(0)..=(ox + r).min(opacity.width() as isize - 1) {
+            if !in_radius(x - ox, y - oy, radius) {
+                continue;
+            }
+
+            visible[(x as usize, y as usize)] = POINTS.iter().any(|(fx, fy)| {
+                let from = (ox as f64 + fx, oy as f64 + fy);
+                POINTS.iter().any(|(tx, ty)| {
+                    let to = (x as f64 + tx, y as f64 + ty);
+                    clear(opacity, from, to, &[(ox, oy), (x, y)])
+                })
+            });
+        }
+    }
+    visible
+}
+
+/// Whether a segment passes through the inside of a cell, not just along its edges
+fn crosses(from: (f64, f64), to: (f64, f64), (x, y): (isize, isize)) -> bool {
+    let mut enter: f64 = 0.;
+    let mut exit: f64 = 1.;
+    for (start, end, cell) in [(from.0, to.0, x), (from.1, to.1, y)] {
+        let delta = end - start;
+        if delta == 0. {
+            if start <= cell as f64 || start >= (cell + 1) as f64 {
+                return false;
+            }
+            continue;
+        }
+
+        let (a, b) = (
+            (cell as f64 - start) / delta,
+            ((cell + 1) as f64 - start) / delta,
+        );
+        enter = enter.max(a.min(b));
+        exit = exit.min(a.max(b));
+    }
+    exit - enter > 1e-9
+}
+
+/// Whether the segment between two points avoids the inside of every opaque cell,
+/// other than the cells in `ignore`
+fn clear(
+    opacity: &Grid<bool>,
+    from: (f64, f64),
+    to: (f64, f64),
+    ignore: &[(isize, isize)],
+) -> bool {
+    let opaque = |x: isize, y: isize| {
+        !ignore.contains(&(x, y)) && opacity.in_bounds(x, y) && opacity[(x as usize, y as usize)]
+    };
+    let blocks = |x: isize, y: isize| opaque(x, y) && crosses(from, to, (x, y));
+
+    // A segment along a grid line only grazes the cells on either side, so check
+    // for a seam between two opaque cells that it would slip through
+    if from.1 == to.1 && from.1.fract() == 0. {
+        let y = from.1 as isize;
+        let mut cells = from.0.min(to.0).floor() as isize..from.0.max(to.0).ceil() as isize;
+        if cells.any(|x| opaque(x, y - 1) && opaque(x, y)) {
+            return false;
+        }
+    }
+    if from.0 == to.0 && from.0.fract() == 0. {
+        let x = from.0 as isize;
+        let mut cells = from.1.min(to.1).floor() as isize..from.1.max(to.1).ceil() as isize;
+        if cells.any(|y| opaque(x - 1, y) && opaque(x, y)) {
+            return false;
+        }
+    }
+
+    // Walk the cells along the segment, measuring progress from 0.0 to 1.0
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let (mut x, mut y) = (from.0.floor() as isize, from.1.floor() as isize);
+    let (step_x, step_y) = (dx.signum() as isize, dy.signum() as isize);
+    let boundary = |position: f64, cell: isize, step: isize, delta: f64| match step {
+        0 => f64::INFINITY,
+        1 => ((cell + 1) as f64 - position) / delta,
+        _ => (cell as f64 - position) / delta,
+    };
+    let mut next_x = boundary(from.0, x, step_x, dx);
+    let mut next_y = boundary(from.1, y, step_y, dy);
+    let (each_x, each_y) = ((1. / dx).abs(), (1. / dy).abs());
+
+    if blocks(x, y) {
+        return false;
+    }
+    while next_x.min(next_y) < 1. {
+        let (nx, ny) = (next_x, next_y);
+        if nx <= ny {
+            x += step_x;
+            next_x += each_x;
+        }
+        if ny <= nx {
+            y += step_y;
+            next_y += each_y;
+        }
+
+        if blocks(x, y) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Cells on the line from `from` to `to`, including both ends
+pub fn line(from: (usize, usize), to: (usize, usize)) -> Vec<(usize, usize)> {
+    let (mut x, mut y) = (from.0 as isize, from.1 as isize);
+    let (tx, ty) = (to.0 as isize, to.1 as isize);
+    let (dx, dy) = ((tx - x).abs(), -(ty - y).abs());
+    let (sx, sy) = ((tx - x).signum(), (ty - y).signum());
+    let mut error = dx + dy;
+
+    let mut cells = vec![(x as usize, y as usize)];
+    while (x, y) != (tx, ty) {
+        let doubled = error * 2;
+        if doubled >= dy {
+            error += dy;
+            x += sx;
+        }
+        if doubled <= dx {
+            error += dx;
+            y += sy;
+        }
+        cells.push((x as usize, y as usize));
+    }
+    cells
+}
+
+/// Whether `to` can be seen from `from`
+///
+/// The cells between the two ends must all be transparent along the line in
+/// either direction, so the result is the same whichever end looks at the other.
+pub fn line_of_sight(opacity: &Grid<bool>, from: (usize, usize), to: (usize, usize)) -> bool {
+    let clear = |cells: Vec<(usize, usize)>| {
+        let inner = &cells[1..cells.len().saturating_sub(1).max(1)];
+        inner
+            .iter()
+            .all(|(x, y)| opacity.get(*x, *y).is_some_and(|opaque| !opaque))
+    };
+    clear(line(from, to)) || clear(line(to, from))
+}
+
+/// Hide every cell of the buffer that isn't visible
+///
+/// The visibility grid lines up with the top left of the buffer, and cells outside
+/// of it count as hidden. Hidden cells are recolored with `hidden`, for areas the
+/// player remembers, or cleared when it is `None`.
+pub fn mask(buffer: &mut PixelBuffer, visible: &Grid<bool>, hidden: Option<&Color>) {
+    for y in 0..buffer.height() {
+        for x in 0..buffer.width() {
+            if visible.get(x, y).copied().unwrap_or(false) {
+                continue;
+            }
+            if let Some(pixel) = buffer.get_mut(x, y) {
+                match hidden {
+                    Some(color) => pixel.color = color.clone(),
+                    None => *pixel = Pixel::default(),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use std::ops::{Index, IndexMut};
+
+pub mod fov;
+pub mod path;
+
+/// A fixed size 2D grid of values, stored row by row
+///
+/// Used for map data like opacity, movement costs, and noise samples.
+///
+/// # Example
+/// ```
+/// use clige::grid::Grid;
+///
+/// let mut walls = Grid::new(10, 5, false);
+/// walls.set(3, 2, true).unwrap();
+///
+/// assert!(walls[(3, 2)]);
+/// assert_eq!(walls.iter().filter(|(_, _, wall)| **wall).count(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Create a grid with every cell set to `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Set every cell to `value`
+    pub fn fill(&mut self, value: T) {
+        self.cells.fill(value);
+    }
+}
+
+impl<T> Grid<T> {
+    /// Create a grid by calling `f` with the position of each cell
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(width: usize, height: usize, mut f: F) -> Self {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f(x, y));
+            }
+        }
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Create a grid from cells stored row by row, which must fill the grid exactly
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Result<Self, String> {
+        if cells.len() != width * height {
+            return Err(format!(
+                "Expected {} cells for a {}x{} grid, found {}",
+                width * height,
+                width,
+                height,
+                cells.len()
+            ));
+        }
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Create a grid from rows, which must all be the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, String> {
+        let width = rows.first().map_or(0, |row| row.len());
+        if let Some(row) = rows.iter().position(|row| row.len() != width) {
+            return Err(format!(
+                "Row {} has a different length than the first row",
+                row
+            ));
+        }
+
+        Ok(Grid {
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+            width,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether a position is inside of the grid. Takes signed values so neighbors
+    /// past the edge can be checked without wrapping
+    pub fn in_bounds(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.cells.get(y * self.width + x)
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.cells.get_mut(y * self.width + x)
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> Result<(), String> {
+        match self.get_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+                Ok(())
+            }
+            None => Err(format!(
+                "Index out of bounds; expected x < {} and y < {}",
+                self.width, self.height
+            )),
+        }
+    }
+
+    /// Each cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        let width = self.width.max(1);
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (i % width, i / width, cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (usize, usize, &mut T)> {
+        let width = self.width.max(1);
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, cell)| (i % width, i / width, cell))
+    }
+
+    /// Create a grid of the same size by converting each cell
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// The cells row by row
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Positions next to `(x, y)` that are inside of the grid, either the four
+    /// sharing an edge or all eight including diagonals
+    pub fn neighbors(
+        &self,
+        x: usize,
+        y: usize,
+        diagonal: bool,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        const OFFSETS: [(isize, isize); 8] = [
+            (0, -1),
+            (1, 0),
+            (0, 1),
+            (-1, 0),
+            (1, -1),
+            (1, 1),
+            (-1, 1),
+            (-1, -1),
+        ];
+
+        let count = if diagonal { 8 } else { 4 };
+        OFFSETS[..count].iter().filter_map(move |(dx, dy)| {
+            let (nx, ny) = (x as isize + dx, y as isize + dy);
+            self.in_bounds(nx, ny).then_some((nx as usize, ny as usize))
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y).expect("Grid index out of bounds")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(x, y).expect("Grid index out of bounds")
+    }
+}
@@ -0,0 +1,348 @@
+use std::{cmp::Ordering, collections::BinaryHeap, f64::consts::SQRT_2};
+
+use super::Grid;
+
+/// Which neighboring cells a step can move to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Connectivity {
+    /// Up, down, left, and right
+    Four,
+    /// The four directions and the diagonals between them
+    #[default]
+    Eight,
+}
+
+/// A route through a grid
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path {
+    /// Every cell from the start to the goal, including both
+    pub cells: Vec<(usize, usize)>,
+    /// Total cost of every step
+    pub cost: f64,
+}
+
+/// An entry in the open set, ordered so the lowest priority comes out of the heap first
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Open {
+    priority: f64,
+    /// Cost of reaching the cell when it was queued, to tell if a cheaper way was found since
+    cost: f64,
+    index: usize,
+}
+
+impl Eq for Open {}
+
+impl Ord for Open {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .total_cmp(&self.priority)
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+impl PartialOrd for Open {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Finds routes through a [`Grid`] using a cost for each step
+///
+/// The cost function is given the cell a step leaves and the cell it enters, and
+/// returns `None` when the step isn't allowed. Diagonal steps cost `√2` times as much.
+///
+/// # Panics
+/// Searching panics if the cost function returns a negative cost, which would make
+/// the cheapest routes impossible to find.
+///
+/// # Example
+/// ```
+/// use clige::grid::{path::{Connectivity, Pathfinder}, Grid};
+///
+/// let map = Grid::from_rows(
+///     ["#.....", "#.###.", "#...#.", "####.."]
+///         .iter()
+///         .map(|row| row.chars().collect())
+///         .collect(),
+/// )
+/// .unwrap();
+///
+/// let paths = Pathfinder::new(&map, |_from: &char, to: &char| (*to != '#').then_some(1.))
+///     .connectivity(Connectivity::Four);
+/// let path = paths.astar((1, 2), (4, 3)).unwrap();
+///
+/// assert_eq!(path.cells.first(), Some(&(1, 2)));
+/// assert_eq!(path.cost, 10.);
+/// ```
+pub struct Pathfinder<'g, T, F> {
+    grid: &'g Grid<T>,
+    cost: F,
+    connectivity: Connectivity,
+    cut_corners: bool,
+    heuristic: f64,
+}
+
+impl<'g, T, F> Pathfinder<'g, T, F>
+where
+    F: Fn(&T, &T) -> Option<f64>,
+{
+    pub fn new(grid: &'g Grid<T>, cost: F) -> Self {
+        Pathfinder {
+            grid,
+            cost,
+            connectivity: Connectivity::Eight,
+            cut_corners: false,
+            heuristic: 1.,
+        }
+    }
+
+    pub fn connectivity(self, connectivity: Connectivity) -> Self {
+        Pathfinder {
+            connectivity,
+            ..self
+        }
+    }
+
+    /// Allow diagonal steps past the corner of a cell that can't be entered
+    pub fn cut_corners(self, cut_corners: bool) -> Self {
+        Pathfinder {
+            cut_corners,
+            ..self
+        }
+    }
+
+    /// The lowest cost of a single straight step, used by A* to estimate the cost
+    /// left to the goal
+    ///
+    /// Defaults to `1.0`. Lower it if steps can cost less, or paths may not be the
+    /// cheapest. Raising it finds paths faster at the expense of their cost.
+    pub fn heuristic(self, heuristic: f64) -> Self {
+        Pathfinder { heuristic, ..self }
+    }
+
+    fn index(&self, (x, y): (usize, usize)) -> usize {
+        y * self.grid.width() + x
+    }
+
+    fn position(&self, index: usize) -> (usize, usize) {
+        (index % self.grid.width(), index / self.grid.width())
+    }
+
+    /// Cost of a single step, checking corners for diagonal steps
+    fn step(&self, from: (usize, usize), to: (usize, usize)) -> Option<f64> {
+        let cost = (self.cost)(&self.grid[from], &self.grid[to])?;
+        assert!(cost >= 0., "Step costs can't be negative, found {}", cost);
+        if from.0 == to.0 || from.1 == to.1 {
+            return Some(cost);
+        }
+
+        if !self.cut_corners {
+            (self.cost)(&self.grid[from], &self.grid[(to.0, from.1)])?;
+            (self.cost)(&self.grid[from], &self.grid[(from.0, to.1)])?;
+        }
+        Some(cost * SQRT_2)
+    }
+
+    fn neighbors(&self, (x, y): (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.grid
+            .neighbors(x, y, self.connectivity == Connectivity::Eight)
+    }
+
+    fn estimate(&self, from: (usize, usize), to: (usize, usize)) -> f64 {
+        let dx = from.0.abs_diff(to.0) as f64;
+        let dy = from.1.abs_diff(to.1) as f64;
+        let distance = match self.connectivity {
+            Connectivity::Four => dx + dy,
+            Connectivity::Eight => dx.max(dy) + (SQRT_2 - 1.) * dx.min(dy),
+        };
+        distance * self.heuristic
+    }
+
+    /// The cheapest path from `start` to `goal` with A*
+    pub fn astar(&self, start: (usize, usize), goal: (usize, usize)) -> Option<Path> {
+        self.grid.get(start.0, start.1)?;
+        self.grid.get(goal.0, goal.1)?;
+
+        let size = self.grid.width() * self.grid.height();
+        let mut costs = vec![f64::INFINITY; size];
+        let mut parents = vec![usize::MAX; size];
+        let mut open = BinaryHeap::new();
+
+        costs[self.index(start)] = 0.;
+        open.push(Open {
+            priority: self.estimate(start, goal),
+            cost: 0.,
+            index: self.index(start),
+        });
+
+        while let Some(Open { cost, index, .. }) = open.pop() {
+            let current = self.position(index);
+            if current == goal {
+                return Some(self.trace(&parents, index, costs[index]));
+            }
+            if cost > costs[index] {
+                continue;
+            }
+
+            for next in self.neighbors(current) {
+                let Some(step) = self.step(current, next) else {
+                    continue;
+                };
+                let cost = costs[index] + step;
+                let next_index = self.index(next);
+                if cost < costs[next_index] {
+                    costs[next_index] = cost;
+                    parents[next_index] = index;
+                    open.push(Open {
+                        priority: cost + self.estimate(next, goal),
+                        cost,
+                        index: next_index,
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    fn trace(&self, parents: &[usize], mut index: usize, cost: f64) -> Path {
+        let mut cells = vec![self.position(index)];
+        while parents[index] != usize::MAX {
+            index = parents[index];
+            cells.push(self.position(index));
+        }
+        cells.reverse();
+        Path { cells, cost }
+    }
+
+    /// The cost of reaching the nearest of `goals` from every cell
+    pub fn dijkstra(&self, goals: &[(usize, usize)]) -> DijkstraMap {
+        let mut costs = Grid::new(self.grid.width(), self.grid.height(), f64::INFINITY);
+        let mut next = Grid::new(self.grid.width(), self.grid.height(), None);
+        let mut open = BinaryHeap::new();
+
+        for goal in goals
+            .iter()
+            .filter(|(x, y)| self.grid.get(*x, *y).is_some())
+        {
+            costs[*goal] = 0.;
+            open.push(Open {
+                priority: 0.,
+                cost: 0.,
+                index: self.index(*goal),
+            });
+        }
+
+        while let Some(Open { cost, index, .. }) = open.pop() {
+            let current = self.position(index);
+            if cost > costs[current] {
+                continue;
+            }
+
+            // Spread outward from the goals, costing each step in the direction it is walked
+            for previous in self.neighbors(current) {
+                let Some(step) = self.step(previous, current) else {
+                    continue;
+                };
+                let cost = cost + step;
+                if cost < costs[previous] {
+                    costs[previous] = cost;
+                    next[previous] = Some(current);
+                    open.push(Open {
+                        priority: cost,
+                        cost,
+                        index: self.index(previous),
+                    });
+                }
+            }
+        }
+
+        DijkstraMap { costs, next }
+    }
+
+    /// Directions towards the nearest of `goals` from every cell, for moving many agents
+    /// to the same place
+    pub fn flow_field(&self, goals: &[(usize, usize)]) -> FlowField {
+        let map = self.dijkstra(goals);
+        let directions = Grid::from_fn(self.grid.width(), self.grid.height(), |x, y| {
+            let (nx, ny) = map.downhill(x, y)?;
+            Some((
+                (nx as isize - x as isize) as i8,
+                (ny as isize - y as isize) as i8,
+            ))
+        });
+        FlowField { directions }
+    }
+}
+
+/// The cost of reaching the nearest goal from each cell of a grid
+///
+/// Agents walk towards the goals by stepping [`downhill`](DijkstraMap::downhill)
+/// from cell to cell.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DijkstraMap {
+    costs: Grid<f64>,
+    /// The neighbor each cell was reached from while spreading out from the goals
+    next: Grid<Option<(usize, usize)>>,
+}
+
+impl DijkstraMap {
+    /// Cost of reaching a goal from a cell, or `None` if no goal can be reached
+    pub fn cost(&self, x: usize, y: usize) -> Option<f64> {
+        self.costs
+            .get(x, y)
+            .copied()
+            .filter(|cost| cost.is_finite())
+    }
+
+    /// Costs for every cell, infinite where no goal can be reached
+    pub fn costs(&self) -> &Grid<f64> {
+        &self.costs
+    }
+
+    /// The neighbor to step to on the cheapest way to a goal, or `None` at a goal
+    /// or where no goal can be reached
+    ///
+    /// Goals are tracked rather than found by their cost, so this leads to a goal
+    /// even across steps that cost nothing.
+    pub fn downhill(&self, x: usize, y: usize) -> Option<(usize, usize)> {
+        self.next.get(x, y).copied().flatten()
+    }
+
+    /// Cells from `start` to the nearest goal, following the lowest costs
+    pub fn path(&self, start: (usize, usize)) -> Option<Vec<(usize, usize)>> {
+        self.cost(start.0, start.1)?;
+
+        let mut cells = vec![start];
+        let mut current = start;
+        while let Some(next) = self.downhill(current.0, current.1) {
+            cells.push(next);
+            current = next;
+        }
+        Some(cells)
+    }
+}
+
+/// The direction to step from each cell to get closer to the nearest goal
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlowField {
+    directions: Grid<Option<(i8, i8)>>,
+}
+
+impl FlowField {
+    /// Offset of the next step from a cell, or `None` at a goal or where no goal
+    /// can be reached
+    pub fn direction(&self, x: usize, y: usize) -> Option<(isize, isize)> {
+        self.directions
+            .get(x, y)
+            .copied()
+            .flatten()
+            .map(|(dx, dy)| (dx as isize, dy as isize))
+    }
+
+    /// The cell to step to from a cell
+    pub fn next(&self, x: usize, y: usize) -> Option<(usize, usize)> {
+        let (dx, dy) = self.direction(x, y)?;
+        Some(((x as isize + dx) as usize, (y as isize + dy) as usize))
+    }
+}
@@ -1,3 +1,10 @@
 pub mod core;
+pub mod ecs;
+pub mod fx;
 pub mod gen;
+pub mod grid;
+pub mod physics;
+pub mod save;
+pub mod scene;
+pub mod ui;
 pub use macros;
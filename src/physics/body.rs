@@ -0,0 +1,248 @@
+use super::collision::{sweep, Aabb, Axis, TileMap};
+
+/// A moving box with a floating point position, for things that move less than a cell a frame
+///
+/// The position is kept between cells and only snapped to a cell when drawn, so
+/// slow movement still builds up over several frames.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Body {
+    pub aabb: Aabb,
+    /// Cells per second
+    pub velocity: (f64, f64),
+    /// Cells per second squared, applied on top of gravity
+    pub acceleration: (f64, f64),
+    /// Fraction of velocity lost per second, from `0.0` for none
+    pub drag: f64,
+    /// How strongly gravity pulls on the body, `0.0` to ignore it
+    pub gravity_scale: f64,
+    /// Whether the body stops against solid tiles
+    pub solid: bool,
+    previous: (f64, f64),
+    grounded: bool,
+}
+
+impl Body {
+    pub fn new(aabb: Aabb) -> Self {
+        Body {
+            aabb,
+            velocity: (0., 0.),
+            acceleration: (0., 0.),
+            drag: 0.,
+            gravity_scale: 1.,
+            solid: true,
+            previous: (aabb.x, aabb.y),
+            grounded: false,
+        }
+    }
+
+    pub fn velocity(self, x: f64, y: f64) -> Self {
+        Body {
+            velocity: (x, y),
+            ..self
+        }
+    }
+
+    pub fn acceleration(self, x: f64, y: f64) -> Self {
+        Body {
+            acceleration: (x, y),
+            ..self
+        }
+    }
+
+    pub fn drag(self, drag: f64) -> Self {
+        Body { drag, ..self }
+    }
+
+    pub fn gravity_scale(self, gravity_scale: f64) -> Self {
+        Body {
+            gravity_scale,
+            ..self
+        }
+    }
+
+    /// Let the body pass through solid tiles
+    pub fn ghost(self) -> Self {
+        Body {
+            solid: false,
+            ..self
+        }
+    }
+
+    pub fn position(&self) -> (f64, f64) {
+        (self.aabb.x, self.aabb.y)
+    }
+
+    /// Move the body without it sliding between the old and new position when interpolated
+    pub fn teleport(&mut self, x: f64, y: f64) {
+        self.aabb.x = x;
+        self.aabb.y = y;
+        self.previous = (x, y);
+    }
+
+    /// Whether the body was standing on a solid tile after the last step
+    pub fn grounded(&self) -> bool {
+        self.grounded
+    }
+
+    /// The cell the body's top left corner is drawn in
+    pub fn cell(&self) -> (i64, i64) {
+        (self.aabb.x.round() as i64, self.aabb.y.round() as i64)
+    }
+
+    /// Position between the previous and current step, where `alpha` comes from
+    /// [`Physics::alpha`]
+    ///
+    /// Drawing the interpolated position keeps movement smooth when the frame rate
+    /// doesn't match the physics step.
+    pub fn interpolated(&self, alpha: f64) -> (f64, f64) {
+        let (px, py) = self.previous;
+        (
+            px + (self.aabb.x - px) * alpha,
+            py + (self.aabb.y - py) * alpha,
+        )
+    }
+
+    /// Whether a solid tile is directly beneath the body
+    pub fn on_ground<M: TileMap + ?Sized>(&self, map: &M) -> bool {
+        let bottom = self.aabb.bottom();
+        if (bottom - bottom.round()).abs() > 1e-6 {
+            return false;
+        }
+
+        let (left, _, right, _) = self.aabb.cells();
+        (left..right).any(|x| map.is_solid(x, bottom.round() as i64))
+    }
+}
+
+/// Moves [`Body`]s with a fixed timestep
+///
+/// Frames rarely take the same time, so [`Physics::update`] collects the elapsed
+/// time and runs as many steps of exactly [`Physics::step`] seconds as fit. Each
+/// step integrates acceleration and gravity into velocity, applies drag, and then
+/// moves the body through the tile map.
+///
+/// # Example
+/// ```
+/// use clige::physics::{Aabb, Body, Physics};
+///
+/// let floor = |_x: i64, y: i64| y >= 10;
+/// let mut physics = Physics::new(1. / 60.).gravity(0., 40.);
+/// let mut bodies = [Body::new(Aabb::new(3., 0., 1., 2.))];
+///
+/// for _ in 0..120 {
+///     physics.update(1. / 30., &mut bodies, &floor);
+/// }
+///
+/// assert!(bodies[0].grounded());
+/// assert_eq!(bodies[0].aabb.bottom(), 10.);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Physics {
+    step: f64,
+    gravity: (f64, f64),
+    max_steps: usize,
+    accumulator: f64,
+}
+
+impl Default for Physics {
+    fn default() -> Self {
+        Physics::new(1. / 60.)
+    }
+}
+
+impl Physics {
+    /// Create physics that steps `step` seconds at a time
+    ///
+    /// The step must be positive. Physics with a zero or negative step never runs a step.
+    pub fn new(step: f64) -> Self {
+        Physics {
+            step,
+            gravity: (0., 0.),
+            max_steps: 8,
+            accumulator: 0.,
+        }
+    }
+
+    /// Acceleration applied to every body, in cells per second squared. Positive y is down
+    pub fn gravity(self, x: f64, y: f64) -> Self {
+        Physics {
+            gravity: (x, y),
+            ..self
+        }
+    }
+
+    /// Most steps run in a single update, so a slow frame can't cause a spiral of
+    /// ever longer updates. Time past the limit is dropped
+    pub fn max_steps(self, max_steps: usize) -> Self {
+        Physics { max_steps, ..self }
+    }
+
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    /// How far between the last step and the next the current time is, from `0.0` to `1.0`
+    pub fn alpha(&self) -> f64 {
+        if self.step <= 0. {
+            return 1.;
+        }
+        (self.accumulator / self.step).clamp(0., 1.)
+    }
+
+    /// Add `delta` seconds and run every whole step that fits, returning how many ran
+    pub fn update<M: TileMap + ?Sized>(
+        &mut self,
+        delta: f64,
+        bodies: &mut [Body],
+        map: &M,
+    ) -> usize {
+        if self.step <= 0. {
+            return 0;
+        }
+        self.accumulator += delta;
+
+        let mut steps = 0;
+        while self.accumulator >= self.step && steps < self.max_steps {
+            for body in bodies.iter_mut() {
+                self.integrate(body, map);
+            }
+            self.accumulator -= self.step;
+            steps += 1;
+        }
+
+        if steps == self.max_steps {
+            self.accumulator = self.accumulator.min(self.step);
+        }
+        steps
+    }
+
+    /// Run a single step on one body
+    pub fn integrate<M: TileMap + ?Sized>(&self, body: &mut Body, map: &M) {
+        let dt = self.step;
+        body.previous = body.position();
+
+        let (vx, vy) = body.velocity;
+        let ax = body.acceleration.0 + self.gravity.0 * body.gravity_scale;
+        let ay = body.acceleration.1 + self.gravity.1 * body.gravity_scale;
+        let damping = (1. - body.drag * dt).max(0.);
+        body.velocity = ((vx + ax * dt) * damping, (vy + ay * dt) * damping);
+
+        let (dx, dy) = (body.velocity.0 * dt, body.velocity.1 * dt);
+        if !body.solid {
+            body.aabb = body.aabb.translate(dx, dy);
+            body.grounded = false;
+            return;
+        }
+
+        let result = sweep(&body.aabb, dx, dy, map);
+        body.aabb = result.aabb;
+        for hit in result.hits.iter() {
+            match hit.axis {
+                Axis::X => body.velocity.0 = 0.,
+                Axis::Y => body.velocity.1 = 0.,
+            }
+        }
+        body.grounded =
+            result.hit(Axis::Y).is_some_and(|hit| hit.normal < 0.) || body.on_ground(map);
+    }
+}
@@ -0,0 +1,433 @@
+use std::{collections::HashSet, hash::Hash};
+
+use crate::core::data::Rect;
+
+/// An axis aligned bounding box with a floating point position and size, measured in cells
+///
+/// The left and top edges are inside the box and the right and bottom edges are not,
+/// the same as [`Rect`]. A box from `0.0` to `1.0` covers exactly one cell.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Aabb {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Aabb {
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Aabb {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// A box covering a single cell
+    pub fn cell(x: i64, y: i64) -> Self {
+        Aabb::new(x as f64, y as f64, 1., 1.)
+    }
+
+    pub fn left(&self) -> f64 {
+        self.x
+    }
+
+    pub fn top(&self) -> f64 {
+        self.y
+    }
+
+    pub fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+
+    pub fn center(&self) -> (f64, f64) {
+        (self.x + self.width / 2., self.y + self.height / 2.)
+    }
+
+    /// The same box moved by an amount
+    pub fn translate(&self, dx: f64, dy: f64) -> Aabb {
+        Aabb {
+            x: self.x + dx,
+            y: self.y + dy,
+            ..*self
+        }
+    }
+
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        x >= self.left() && x < self.right() && y >= self.top() && y < self.bottom()
+    }
+
+    /// Whether the boxes share any area. Boxes that only touch along an edge don't overlap
+    pub fn overlaps(&self, other: &Aabb) -> bool {
+        self.left() < other.right()
+            && other.left() < self.right()
+            && self.top() < other.bottom()
+            && other.top() < self.bottom()
+    }
+
+    /// The area shared by both boxes
+    pub fn intersection(&self, other: &Aabb) -> Option<Aabb> {
+        if !self.overlaps(other) {
+            return None;
+        }
+
+        let left = self.left().max(other.left());
+        let top = self.top().max(other.top());
+        Some(Aabb::new(
+            left,
+            top,
+            self.right().min(other.right()) - left,
+            self.bottom().min(other.bottom()) - top,
+        ))
+    }
+
+    /// The smallest move that pushes this box out of `other`, along a single axis
+    ///
+    /// Returns `None` if the boxes don't overlap.
+    pub fn penetration(&self, other: &Aabb) -> Option<(f64, f64)> {
+        let overlap = self.intersection(other)?;
+        let (x, y) = self.center();
+        let (ox, oy) = other.center();
+
+        Some(match overlap.width < overlap.height {
+            true if x < ox => (-overlap.width, 0.),
+            true => (overlap.width, 0.),
+            false if y < oy => (0., -overlap.height),
+            false => (0., overlap.height),
+        })
+    }
+
+    /// Cells the box covers, as `(left, top, right, bottom)` with exclusive right and bottom
+    pub fn cells(&self) -> (i64, i64, i64, i64) {
+        (
+            self.left().floor() as i64,
+            self.top().floor() as i64,
+            self.right().ceil() as i64,
+            self.bottom().ceil() as i64,
+        )
+    }
+
+    /// Snap the box to the cells it is drawn in, clipped to positive coordinates
+    pub fn to_rect(&self) -> Rect {
+        let (left, top) = (self.x.round().max(0.), self.y.round().max(0.));
+        Rect::from([
+            left as usize,
+            top as usize,
+            (left + self.width.round()).max(0.) as usize,
+            (top + self.height.round()).max(0.) as usize,
+        ])
+    }
+}
+
+impl From<Rect> for Aabb {
+    fn from(value: Rect) -> Self {
+        Aabb::new(
+            value.left as f64,
+            value.top as f64,
+            value.width() as f64,
+            value.height() as f64,
+        )
+    }
+}
+
+/// The shape something collides with
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shape {
+    /// A single whole cell
+    Cell(i64, i64),
+    Aabb(Aabb),
+}
+
+impl Shape {
+    pub fn bounds(&self) -> Aabb {
+        match self {
+            Shape::Cell(x, y) => Aabb::cell(*x, *y),
+            Shape::Aabb(aabb) => *aabb,
+        }
+    }
+
+    pub fn overlaps(&self, other: &Shape) -> bool {
+        match (self, other) {
+            (Shape::Cell(x, y), Shape::Cell(ox, oy)) => x == ox && y == oy,
+            _ => self.bounds().overlaps(&other.bounds()),
+        }
+    }
+}
+
+impl From<Aabb> for Shape {
+    fn from(value: Aabb) -> Self {
+        Shape::Aabb(value)
+    }
+}
+
+/// A grid of cells that are either solid or open
+///
+/// Implemented for closures taking a cell position, and for rows of `bool`s where
+/// anything outside of the rows is open.
+pub trait TileMap {
+    fn is_solid(&self, x: i64, y: i64) -> bool;
+}
+
+impl<F: Fn(i64, i64) -> bool> TileMap for F {
+    fn is_solid(&self, x: i64, y: i64) -> bool {
+        self(x, y)
+    }
+}
+
+impl TileMap for Vec<Vec<bool>> {
+    fn is_solid(&self, x: i64, y: i64) -> bool {
+        if x < 0 || y < 0 {
+            return false;
+        }
+        self.get(y as usize)
+            .and_then(|row| row.get(x as usize))
+            .copied()
+            .unwrap_or(false)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+/// A solid tile that stopped a moving box
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hit {
+    pub axis: Axis,
+    /// Direction pointing away from the tile, `-1.0` or `1.0` along the axis
+    pub normal: f64,
+    pub tile: (i64, i64),
+}
+
+/// Result of moving a box through a [`TileMap`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sweep {
+    /// Where the box ended up
+    pub aabb: Aabb,
+    /// Tiles that stopped the box, at most one per axis
+    pub hits: Vec<Hit>,
+}
+
+impl Sweep {
+    pub fn hit(&self, axis: Axis) -> Option<&Hit> {
+        self.hits.iter().find(|hit| hit.axis == axis)
+    }
+}
+
+/// Move a box by `(dx, dy)`, stopping it against the first solid tile on each axis
+///
+/// The box moves along the x axis first and then the y axis, checking every cell
+/// it passes through, so fast moving boxes can't tunnel through thin walls. Tiles
+/// the box already overlaps are ignored so that it can't get stuck in them.
+///
+/// # Example
+/// ```
+/// use clige::physics::collision::{sweep, Aabb, Axis};
+///
+/// // A floor along y = 5
+/// let floor = |_x: i64, y: i64| y == 5;
+/// let result = sweep(&Aabb::new(2., 1., 1., 1.), 0., 10., &floor);
+///
+/// assert_eq!(result.aabb.y, 4.);
+/// assert_eq!(result.hit(Axis::Y).unwrap().normal, -1.);
+/// ```
+pub fn sweep<M: TileMap + ?Sized>(aabb: &Aabb, dx: f64, dy: f64, map: &M) -> Sweep {
+    let mut hits = Vec::new();
+    let (aabb, hit) = move_axis(aabb, dx, Axis::X, map);
+    hits.extend(hit);
+    let (aabb, hit) = move_axis(&aabb, dy, Axis::Y, map);
+    hits.extend(hit);
+    Sweep { aabb, hits }
+}
+
+fn move_axis<M: TileMap + ?Sized>(
+    aabb: &Aabb,
+    delta: f64,
+    axis: Axis,
+    map: &M,
+) -> (Aabb, Option<Hit>) {
+    if delta == 0. {
+        return (*aabb, None);
+    }
+
+    let (left, top, right, bottom) = aabb.cells();
+    // The span of cells across the direction of travel
+    let (across_start, across_end) = match axis {
+        Axis::X => (top, bottom),
+        Axis::Y => (left, right),
+    };
+    let (start, end) = match axis {
+        Axis::X => (aabb.left(), aabb.right()),
+        Axis::Y => (aabb.top(), aabb.bottom()),
+    };
+
+    // The first solid tile in a line of cells across the direction of travel
+    let blocking = |along: i64| {
+        (across_start..across_end)
+            .map(|across| match axis {
+                Axis::X => (along, across),
+                Axis::Y => (across, along),
+            })
+            .find(|(x, y)| map.is_solid(*x, *y))
+    };
+
+    // Snap to the edge of a tile that was hit, so resting boxes sit exactly on cell boundaries
+    let (position, hit) = if delta > 0. {
+        let first = end.ceil() as i64;
+        let last = (end + delta).ceil() as i64 - 1;
+        match (first..=last).find_map(|along| Some((along, blocking(along)?))) {
+            Some((along, tile)) => (along as f64 - (end - start), Some((tile, -1.))),
+            None => (start + delta, None),
+        }
+    } else {
+        let first = start.floor() as i64 - 1;
+        let last = (start + delta).floor() as i64;
+        match (last..=first)
+            .rev()
+            .find_map(|along| Some((along, blocking(along)?)))
+        {
+            Some((along, tile)) => ((along + 1) as f64, Some((tile, 1.))),
+            None => (start + delta, None),
+        }
+    };
+
+    let aabb = match axis {
+        Axis::X => Aabb {
+            x: position,
+            ..*aabb
+        },
+        Axis::Y => Aabb {
+            y: position,
+            ..*aabb
+        },
+    };
+    let hit = hit.map(|(tile, normal)| Hit { axis, normal, tile });
+    (aabb, hit)
+}
+
+/// Push a box out of every solid tile it overlaps, each time along the shortest axis
+///
+/// Returns the total move that was applied.
+pub fn resolve_tiles<M: TileMap + ?Sized>(aabb: &mut Aabb, map: &M) -> (f64, f64) {
+    let mut total = (0., 0.);
+    let (left, top, right, bottom) = aabb.cells();
+
+    for y in top..bottom {
+        for x in left..right {
+            if !map.is_solid(x, y) {
+                continue;
+            }
+            if let Some((dx, dy)) = aabb.penetration(&Aabb::cell(x, y)) {
+                *aabb = aabb.translate(dx, dy);
+                total = (total.0 + dx, total.1 + dy);
+            }
+        }
+    }
+    total
+}
+
+/// Push two overlapping boxes apart along the shortest axis
+///
+/// `weight` is how much of the move is applied to `a`, from `0.0` where only `b`
+/// moves to `1.0` where only `a` moves. Returns whether the boxes overlapped.
+pub fn resolve(a: &mut Aabb, b: &mut Aabb, weight: f64) -> bool {
+    let Some((dx, dy)) = a.penetration(b) else {
+        return false;
+    };
+
+    let weight = weight.clamp(0., 1.);
+    *a = a.translate(dx * weight, dy * weight);
+    *b = b.translate(-dx * (1. - weight), -dy * (1. - weight));
+    true
+}
+
+/// A change in whether two things are touching
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Contact<K> {
+    /// The pair started overlapping this frame
+    Started(K, K),
+    /// The pair stopped overlapping this frame
+    Ended(K, K),
+}
+
+/// Turns the pairs that overlap each frame into [`Contact`] events
+///
+/// Pairs are unordered, so `(a, b)` and `(b, a)` are the same contact.
+#[derive(Debug, Clone)]
+pub struct Contacts<K> {
+    current: HashSet<(K, K)>,
+}
+
+impl<K: Copy + Eq + Hash + Ord> Default for Contacts<K> {
+    fn default() -> Self {
+        Contacts::new()
+    }
+}
+
+impl<K: Copy + Eq + Hash + Ord> Contacts<K> {
+    pub fn new() -> Self {
+        Contacts {
+            current: HashSet::new(),
+        }
+    }
+
+    fn key(a: K, b: K) -> (K, K) {
+        (a.min(b), a.max(b))
+    }
+
+    /// Replace the overlapping pairs with this frame's, returning what changed
+    pub fn update<I: IntoIterator<Item = (K, K)>>(&mut self, pairs: I) -> Vec<Contact<K>> {
+        let pairs = pairs
+            .into_iter()
+            .map(|(a, b)| Self::key(a, b))
+            .collect::<HashSet<_>>();
+
+        let mut events = pairs
+            .difference(&self.current)
+            .map(|(a, b)| Contact::Started(*a, *b))
+            .collect::<Vec<_>>();
+        events.extend(
+            self.current
+                .difference(&pairs)
+                .map(|(a, b)| Contact::Ended(*a, *b)),
+        );
+        events.sort_by_key(|event| match event {
+            Contact::Started(a, b) => (0, *a, *b),
+            Contact::Ended(a, b) => (1, *a, *b),
+        });
+
+        self.current = pairs;
+        events
+    }
+
+    pub fn touching(&self, a: K, b: K) -> bool {
+        self.current.contains(&Self::key(a, b))
+    }
+
+    /// Every pair currently overlapping
+    pub fn pairs(&self) -> impl Iterator<Item = (K, K)> + '_ {
+        self.current.iter().copied()
+    }
+}
+
+/// Every pair of boxes that overlap, checking each pair
+///
+/// Fine for a handful of boxes; larger numbers should narrow down candidates first.
+pub fn overlapping<K: Copy>(boxes: &[(K, Aabb)]) -> Vec<(K, K)> {
+    let mut pairs = Vec::new();
+    for (i, (a, first)) in boxes.iter().enumerate() {
+        for (b, second) in boxes[i + 1..].iter() {
+            if first.overlaps(second) {
+                pairs.push((*a, *b));
+            }
+        }
+    }
+    pairs
+}
@@ -0,0 +1,7 @@
+pub mod body;
+pub mod collision;
+pub mod spatial;
+
+pub use body::{Body, Physics};
+pub use collision::{Aabb, Shape, TileMap};
+pub use spatial::{Quadtree, SpatialHash, SpatialIndex};
@@ -0,0 +1,465 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::core::data::Rect;
+
+/// Squared distance from a position to the nearest cell of a rect, `0` when inside it
+fn distance(rect: &Rect, x: usize, y: usize) -> usize {
+    let dx = match x {
+        x if x < rect.left => rect.left - x,
+        x if x >= rect.right => x + 1 - rect.right.max(rect.left + 1),
+        _ => 0,
+    };
+    let dy = match y {
+        y if y < rect.top => rect.top - y,
+        y if y >= rect.bottom => y + 1 - rect.bottom.max(rect.top + 1),
+        _ => 0,
+    };
+    dx * dx + dy * dy
+}
+
+/// A structure that finds keyed [`Rect`]s near an area without checking every one
+///
+/// Used as a broadphase: it narrows down which pairs of things could be colliding
+/// before the exact shapes are compared.
+pub trait SpatialIndex<K: Copy> {
+    /// Add a rect, replacing the rect already stored for the key
+    fn insert(&mut self, key: K, rect: Rect);
+
+    /// Remove a key, returning its rect
+    fn remove(&mut self, key: K) -> Option<Rect>;
+
+    fn get(&self, key: K) -> Option<Rect>;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn clear(&mut self);
+
+    /// Keys whose rects overlap `area`
+    fn query(&self, area: &Rect) -> Vec<K>;
+
+    /// Keys whose rects contain the cell at `(x, y)`
+    fn point(&self, x: usize, y: usize) -> Vec<K>;
+
+    /// The key whose rect is closest to the cell at `(x, y)`
+    fn nearest(&self, x: usize, y: usize) -> Option<K>;
+
+    /// Move a key to a new rect. Returns `false` if the key wasn't stored
+    fn relocate(&mut self, key: K, rect: Rect) -> bool {
+        match self.remove(key) {
+            Some(_) => {
+                self.insert(key, rect);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Every pair of keys whose rects overlap, each pair given once
+    fn pairs(&self) -> Vec<(K, K)>;
+}
+
+/// A grid of equally sized buckets, each listing the rects that touch it
+///
+/// Fast when things are spread out and about the same size as a bucket.
+///
+/// # Example
+/// ```
+/// use clige::core::data::Rect;
+/// use clige::physics::spatial::{SpatialHash, SpatialIndex};
+///
+/// let mut hash = SpatialHash::new(8);
+/// hash.insert("player", Rect::from([3, 3, 4, 5]));
+/// hash.insert("slime", Rect::from([40, 10, 42, 11]));
+///
+/// assert_eq!(hash.query(&Rect::from([0, 0, 10, 10])), vec!["player"]);
+/// assert_eq!(hash.nearest(30, 10), Some("slime"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpatialHash<K> {
+    size: usize,
+    buckets: HashMap<(usize, usize), Vec<K>>,
+    rects: HashMap<K, Rect>,
+}
+
+impl<K: Copy + Eq + Hash> SpatialHash<K> {
+    /// Create a spatial hash with square buckets `size` cells wide
+    pub fn new(size: usize) -> Self {
+        SpatialHash {
+            size: size.max(1),
+            buckets: HashMap::new(),
+            rects: HashMap::new(),
+        }
+    }
+
+    /// Buckets a rect touches. Empty rects are kept in the bucket of their corner
+    fn buckets_of(&self, rect: &Rect) -> impl Iterator<Item = (usize, usize)> {
+        let size = self.size;
+        let (left, top) = (rect.left / size, rect.top / size);
+        let right = rect.right.max(rect.left + 1).saturating_sub(1) / size;
+        let bottom = rect.bottom.max(rect.top + 1).saturating_sub(1) / size;
+        (top..=bottom).flat_map(move |y| (left..=right).map(move |x| (x, y)))
+    }
+
+    fn candidates(&self, area: &Rect) -> HashSet<K> {
+        self.buckets_of(area)
+            .filter_map(|bucket| self.buckets.get(&bucket))
+            .flatten()
+            .copied()
+            .collect()
+    }
+}
+
+impl<K: Copy + Eq + Hash> SpatialIndex<K> for SpatialHash<K> {
+    fn insert(&mut self, key: K, rect: Rect) {
+        self.remove(key);
+        for bucket in self.buckets_of(&rect).collect::<Vec<_>>() {
+            self.buckets.entry(bucket).or_default().push(key);
+        }
+        self.rects.insert(key, rect);
+    }
+
+    fn remove(&mut self, key: K) -> Option<Rect> {
+        let rect = self.rects.remove(&key)?;
+        for bucket in self.buckets_of(&rect).collect::<Vec<_>>() {
+            if let Some(keys) = self.buckets.get_mut(&bucket) {
+                keys.retain(|k| *k != key);
+                if keys.is_empty() {
+                    self.buckets.remove(&bucket);
+                }
+            }
+        }
+        Some(rect)
+    }
+
+    fn get(&self, key: K) -> Option<Rect> {
+        self.rects.get(&key).copied()
+    }
+
+    fn len(&self) -> usize {
+        self.rects.len()
+    }
+
+    fn clear(&mut self) {
+        self.buckets.clear();
+        self.rects.clear();
+    }
+
+    fn query(&self, area: &Rect) -> Vec<K> {
+        self.candidates(area)
+            .into_iter()
+            .filter(|key| self.rects[key].overlaps(area))
+            .collect()
+    }
+
+    fn point(&self, x: usize, y: usize) -> Vec<K> {
+        self.buckets
+            .get(&(x / self.size, y / self.size))
+            .into_iter()
+            .flatten()
+            .filter(|key| self.rects[*key].contains(x, y))
+            .copied()
+            .collect()
+    }
+
+    /// Searches rings of buckets outward from the position until nothing closer can remain
+    fn nearest(&self, x: usize, y: usize) -> Option<K> {
+        let (cx, cy) = ((x / self.size) as isize, (y / self.size) as isize);
+        let limit = self
+            .buckets
+            .keys()
+            .map(|(bx, by)| (*bx as isize - cx).abs().max((*by as isize - cy).abs()))
+            .max()?;
+        let mut best: Option<(usize, K)> = None;
+
+        for ring in 0..=limit {
+            for by in (cy - ring)..=(cy + ring) {
+                for bx in (cx - ring)..=(cx + ring) {
+                    let edge = (by - cy).abs() == ring || (bx - cx).abs() == ring;
+                    if !edge || bx < 0 || by < 0 {
+                        continue;
+                    }
+                    for key in self
+                        .buckets
+                        .get(&(bx as usize, by as usize))
+                        .into_iter()
+                        .flatten()
+                    {
+                        let d = distance(&self.rects[key], x, y);
+                        if best.is_none_or(|(b, _)| d < b) {
+                            best = Some((d, *key));
+                        }
+                    }
+                }
+            }
+
+            // Every rect in the next ring is at least this far away
+            let reach = ring as usize * self.size + 1;
+            if best.is_some_and(|(d, _)| d <= reach * reach) {
+                break;
+            }
+        }
+
+        best.map(|(_, key)| key)
+    }
+
+    fn pairs(&self) -> Vec<(K, K)> {
+        let mut seen = HashSet::new();
+        let mut pairs = Vec::new();
+        for keys in self.buckets.values() {
+            for (i, a) in keys.iter().enumerate() {
+                for b in keys[i + 1..].iter() {
+                    if seen.contains(&(*b, *a)) || !seen.insert((*a, *b)) {
+                        continue;
+                    }
+                    if self.rects[a].overlaps(&self.rects[b]) {
+                        pairs.push((*a, *b));
+                    }
+                }
+            }
+        }
+        pairs
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Node<K> {
+    bounds: Rect,
+    depth: usize,
+    /// Rects that don't fit entirely inside of a single child
+    items: Vec<(K, Rect)>,
+    children: Option<Box<[Node<K>; 4]>>,
+}
+
+impl<K: Copy + Eq> Node<K> {
+    fn new(bounds: Rect, depth: usize) -> Self {
+        Node {
+            bounds,
+            depth,
+            items: Vec::new(),
+            children: None,
+        }
+    }
+
+    fn child_for(&mut self, rect: &Rect) -> Option<&mut Node<K>> {
+        self.children
+            .as_deref_mut()?
+            .iter_mut()
+            .find(|child| child.bounds.encloses(rect))
+    }
+
+    fn insert(&mut self, key: K, rect: Rect, capacity: usize, max_depth: usize) {
+        if let Some(child) = self.child_for(&rect) {
+            child.insert(key, rect, capacity, max_depth);
+            return;
+        }
+
+        self.items.push((key, rect));
+        if self.children.is_none()
+            && self.items.len() > capacity
+            && self.depth < max_depth
+            && self.bounds.width() >= 2
+            && self.bounds.height() >= 2
+        {
+            self.split(capacity, max_depth);
+        }
+    }
+
+    fn split(&mut self, capacity: usize, max_depth: usize) {
+        let Rect {
+            left,
+            top,
+            right,
+            bottom,
+        } = self.bounds;
+        let (mx, my) = (left + (right - left) / 2, top + (bottom - top) / 2);
+        let depth = self.depth + 1;
+        self.children = Some(Box::new([
+            Node::new(Rect::from([left, top, mx, my]), depth),
+            Node::new(Rect::from([mx, top, right, my]), depth),
+            Node::new(Rect::from([left, my, mx, bottom]), depth),
+            Node::new(Rect::from([mx, my, right, bottom]), depth),
+        ]));
+
+        for (key, rect) in std::mem::take(&mut self.items) {
+            self.insert(key, rect, capacity, max_depth);
+        }
+    }
+
+    fn remove(&mut self, key: K, rect: &Rect) -> bool {
+        if let Some(index) = self.items.iter().position(|(k, _)| *k == key) {
+            self.items.swap_remove(index);
+            return true;
+        }
+        self.child_for(rect)
+            .is_some_and(|child| child.remove(key, rect))
+    }
+
+    fn query(&self, area: &Rect, out: &mut Vec<K>) {
+        out.extend(
+            self.items
+                .iter()
+                .filter(|(_, rect)| rect.overlaps(area))
+                .map(|(key, _)| *key),
+        );
+        for child in self.children.iter().flat_map(|c| c.iter()) {
+            if child.bounds.overlaps(area) {
+                child.query(area, out);
+            }
+        }
+    }
+
+    fn nearest(&self, x: usize, y: usize, best: &mut Option<(usize, K)>) {
+        for (key, rect) in self.items.iter() {
+            let d = distance(rect, x, y);
+            if best.is_none_or(|(b, _)| d < b) {
+                *best = Some((d, *key));
+            }
+        }
+
+        let Some(children) = self.children.as_deref() else {
+            return;
+        };
+        let mut children = children
+            .iter()
+            .map(|child| (distance(&child.bounds, x, y), child))
+            .collect::<Vec<_>>();
+        children.sort_by_key(|(d, _)| *d);
+        for (d, child) in children {
+            if best.is_none_or(|(b, _)| d < b) {
+                child.nearest(x, y, best);
+            }
+        }
+    }
+
+    /// Pairs within this node and its children, where `above` holds the items of every ancestor
+    fn pairs(&self, above: &mut Vec<(K, Rect)>, out: &mut Vec<(K, K)>) {
+        for (i, (a, first)) in self.items.iter().enumerate() {
+            for (b, second) in self.items[i + 1..].iter().chain(above.iter()) {
+                if first.overlaps(second) {
+                    out.push((*a, *b));
+                }
+            }
+        }
+
+        let Some(children) = self.children.as_deref() else {
+            return;
+        };
+        let count = above.len();
+        above.extend(self.items.iter().copied());
+        for child in children.iter() {
+            child.pairs(above, out);
+        }
+        above.truncate(count);
+    }
+}
+
+/// A tree that splits an area into quarters wherever rects are crowded together
+///
+/// Handles things that are bunched up or vary a lot in size better than a
+/// [`SpatialHash`]. Rects outside of the tree's bounds are still stored, but are
+/// checked by every query.
+///
+/// # Example
+/// ```
+/// use clige::core::data::Rect;
+/// use clige::physics::spatial::{Quadtree, SpatialIndex};
+///
+/// let mut tree = Quadtree::new(Rect::from([0, 0, 64, 64]));
+/// for i in 0..20 {
+///     tree.insert(i, Rect::from([i * 3, i * 3, i * 3 + 2, i * 3 + 2]));
+/// }
+///
+/// assert_eq!(tree.point(31, 31), vec![10]);
+/// assert_eq!(tree.nearest(0, 63), Some(10));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Quadtree<K> {
+    root: Node<K>,
+    rects: HashMap<K, Rect>,
+    capacity: usize,
+    max_depth: usize,
+}
+
+impl<K: Copy + Eq + Hash> Quadtree<K> {
+    pub fn new(bounds: Rect) -> Self {
+        Quadtree {
+            root: Node::new(bounds, 0),
+            rects: HashMap::new(),
+            capacity: 8,
+            max_depth: 8,
+        }
+    }
+
+    /// How many rects a node holds before it splits into quarters
+    pub fn capacity(self, capacity: usize) -> Self {
+        Quadtree {
+            capacity: capacity.max(1),
+            ..self
+        }
+    }
+
+    /// How many times the area can be split
+    pub fn max_depth(self, max_depth: usize) -> Self {
+        Quadtree { max_depth, ..self }
+    }
+
+    pub fn bounds(&self) -> Rect {
+        self.root.bounds
+    }
+}
+
+impl<K: Copy + Eq + Hash> SpatialIndex<K> for Quadtree<K> {
+    fn insert(&mut self, key: K, rect: Rect) {
+        self.remove(key);
+        self.root.insert(key, rect, self.capacity, self.max_depth);
+        self.rects.insert(key, rect);
+    }
+
+    fn remove(&mut self, key: K) -> Option<Rect> {
+        let rect = self.rects.remove(&key)?;
+        self.root.remove(key, &rect);
+        Some(rect)
+    }
+
+    fn get(&self, key: K) -> Option<Rect> {
+        self.rects.get(&key).copied()
+    }
+
+    fn len(&self) -> usize {
+        self.rects.len()
+    }
+
+    fn clear(&mut self) {
+        self.root = Node::new(self.root.bounds, 0);
+        self.rects.clear();
+    }
+
+    fn query(&self, area: &Rect) -> Vec<K> {
+        let mut out = Vec::new();
+        self.root.query(area, &mut out);
+        out
+    }
+
+    fn point(&self, x: usize, y: usize) -> Vec<K> {
+        self.query(&Rect::from([x, y, x + 1, y + 1]))
+    }
+
+    fn nearest(&self, x: usize, y: usize) -> Option<K> {
+        let mut best = None;
+        self.root.nearest(x, y, &mut best);
+        best.map(|(_, key)| key)
+    }
+
+    fn pairs(&self) -> Vec<(K, K)> {
+        let mut out = Vec::new();
+        self.root.pairs(&mut Vec::new(), &mut out);
+        out
+    }
+}
@@ -0,0 +1,215 @@
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// Untyped save data, as handed to migrations
+pub use serde_json::Value;
+
+type Migration = Box<dyn Fn(Value) -> Result<Value, String>>;
+
+/// What is written to a slot's file: the game's data along with the format it is in
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    version: u32,
+    saved: u64,
+    data: Value,
+}
+
+/// Details about a save slot, read without loading its data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlotInfo {
+    /// Version of the format the slot was saved in
+    pub version: u32,
+    /// Seconds since the Unix epoch when the slot was saved
+    pub saved: u64,
+}
+
+/// Named save slots stored as JSON files in a directory
+///
+/// Each save records the format `version` it was written with. When the game's state
+/// changes shape, bump the version and add a [`Saves::migration`] that upgrades the
+/// old data so existing saves still load.
+///
+/// Files are written to a temporary file and then renamed over the slot, so a crash
+/// while saving never leaves a half written save behind.
+///
+/// # Example
+/// ```
+/// use clige::save::Saves;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct State {
+///     level: u32,
+///     health: u32,
+/// }
+///
+/// let directory = std::env::temp_dir().join("clige-save-example");
+/// let saves = Saves::new(&directory, 2).migration(1, |mut data| {
+///     // Version 2 added health
+///     data["health"] = 100.into();
+///     Ok(data)
+/// });
+///
+/// saves.save("quick", &State { level: 3, health: 40 }).unwrap();
+/// assert_eq!(saves.load::<State>("quick").unwrap(), State { level: 3, health: 40 });
+/// assert!(saves.slots().unwrap().contains(&"quick".to_string()));
+///
+/// saves.delete("quick").unwrap();
+/// # std::fs::remove_dir_all(directory).ok();
+/// ```
+pub struct Saves {
+    directory: PathBuf,
+    version: u32,
+    migrations: HashMap<u32, Migration>,
+}
+
+impl Saves {
+    /// Store slots in `directory`, which is created on the first save, writing the
+    /// current format `version`
+    pub fn new<P: AsRef<Path>>(directory: P, version: u32) -> Self {
+        Saves {
+            directory: directory.as_ref().to_path_buf(),
+            version,
+            migrations: HashMap::new(),
+        }
+    }
+
+    /// Upgrade data saved in version `from` to version `from + 1`
+    ///
+    /// Migrations are chained, so a save several versions old runs each one in turn.
+    pub fn migration<F>(mut self, from: u32, migrate: F) -> Self
+    where
+        F: Fn(Value) -> Result<Value, String> + 'static,
+    {
+        self.migrations.insert(from, Box::new(migrate));
+        self
+    }
+
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Path of the file a slot is saved to
+    ///
+    /// Slot names may only use letters, numbers, `-` and `_`, so they can't point
+    /// outside of the save directory.
+    pub fn path(&self, slot: &str) -> Result<PathBuf, String> {
+        let valid = !slot.is_empty()
+            && slot
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(format!(
+                "Invalid slot name {:?}; expected letters, numbers, '-' or '_'",
+                slot
+            ));
+        }
+        Ok(self.directory.join(format!("{}.json", slot)))
+    }
+
+    /// Write `state` to a slot, replacing anything saved there before
+    pub fn save<T: Serialize>(&self, slot: &str, state: &T) -> Result<(), String> {
+        let path = self.path(slot)?;
+        let envelope = Envelope {
+            version: self.version,
+            saved: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |time| time.as_secs()),
+            data: serde_json::to_value(state).map_err(|e| e.to_string())?,
+        };
+        let contents = serde_json::to_vec_pretty(&envelope).map_err(|e| e.to_string())?;
+
+        fs::create_dir_all(&self.directory).map_err(|e| e.to_string())?;
+        let temporary = path.with_extension("json.tmp");
+        let written = File::create(&temporary).and_then(|mut file| {
+            file.write_all(&contents)?;
+            file.sync_all()
+        });
+        if let Err(error) = written.and_then(|_| fs::rename(&temporary, &path)) {
+            let _ = fs::remove_file(&temporary);
+            return Err(format!("Failed to save slot {:?}: {}", slot, error));
+        }
+        Ok(())
+    }
+
+    /// Read a slot, running any migrations needed to bring it up to the current version
+    pub fn load<T: DeserializeOwned>(&self, slot: &str) -> Result<T, String> {
+        let mut envelope = self.read(slot)?;
+        if envelope.version > self.version {
+            return Err(format!(
+                "Slot {:?} was saved with version {}, which is newer than {}",
+                slot, envelope.version, self.version
+            ));
+        }
+
+        while envelope.version < self.version {
+            let migrate = self.migrations.get(&envelope.version).ok_or(format!(
+                "No migration from version {} of slot {:?}",
+                envelope.version, slot
+            ))?;
+            envelope.data = migrate(envelope.data)?;
+            envelope.version += 1;
+        }
+        serde_json::from_value(envelope.data).map_err(|e| e.to_string())
+    }
+
+    /// The version and time a slot was saved
+    pub fn info(&self, slot: &str) -> Result<SlotInfo, String> {
+        let envelope = self.read(slot)?;
+        Ok(SlotInfo {
+            version: envelope.version,
+            saved: envelope.saved,
+        })
+    }
+
+    fn read(&self, slot: &str) -> Result<Envelope, String> {
+        let contents = fs::read(self.path(slot)?)
+            .map_err(|e| format!("Failed to read slot {:?}: {}", slot, e))?;
+        serde_json::from_slice(&contents).map_err(|e| format!("Slot {:?} is corrupt: {}", slot, e))
+    }
+
+    pub fn exists(&self, slot: &str) -> bool {
+        self.path(slot).is_ok_and(|path| path.is_file())
+    }
+
+    pub fn delete(&self, slot: &str) -> Result<(), String> {
+        fs::remove_file(self.path(slot)?).map_err(|e| e.to_string())
+    }
+
+    /// Names of every saved slot, sorted
+    pub fn slots(&self) -> Result<Vec<String>, String> {
+        let entries = match fs::read_dir(&self.directory) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(error.to_string()),
+        };
+
+        let mut slots = Vec::new();
+        for entry in entries {
+            let path = entry.map_err(|e| e.to_string())?.path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                if let Some(slot) = path.file_stem().and_then(|stem| stem.to_str()) {
+                    if self.path(slot).is_ok() {
+                        slots.push(slot.to_string());
+                    }
+                }
+            }
+        }
+        slots.sort();
+        Ok(slots)
+    }
+}
@@ -0,0 +1,299 @@
+pub mod transition;
+
+pub use transition::{Edge, Transition};
+
+use crate::core::{
+    buffer::{Buffer, PixelBuffer},
+    data::Pixel,
+    event::Event,
+};
+
+/// A screen of the game, such as a title screen, a level, or a pause menu
+///
+/// Scenes live on a [`SceneStack`]. Only the top scene is updated and handles input,
+/// and each returns a [`Command`] to change which scenes are on the stack.
+pub trait Scene {
+    /// Called when the scene is added to the stack
+    fn enter(&mut self) {}
+
+    /// Called when the scene is removed from the stack
+    fn exit(&mut self) {}
+
+    /// Called when another scene is pushed on top of this one
+    fn pause(&mut self) {}
+
+    /// Called when the scene above this one is popped
+    fn resume(&mut self) {}
+
+    /// Handle an input event while this is the top scene
+    fn handle(&mut self, _event: &Event) -> Command {
+        Command::None
+    }
+
+    /// Advance the scene by `delta` seconds while it is the top scene
+    fn update(&mut self, _delta: f64) -> Command {
+        Command::None
+    }
+
+    /// Draw the scene into the buffer
+    fn render(&mut self, buffer: &mut PixelBuffer);
+
+    /// Whether the scenes below this one are drawn first and show through, like a
+    /// pause menu over a paused game
+    fn transparent(&self) -> bool {
+        false
+    }
+}
+
+/// A change to the scenes on a [`SceneStack`]
+#[derive(Default)]
+pub enum Command {
+    /// Stay on the current scene
+    #[default]
+    None,
+    /// Pause the top scene and put a new one on top of it
+    Push(Box<dyn Scene>, Transition),
+    /// Remove the top scene and resume the one below
+    Pop(Transition),
+    /// Swap the top scene for a new one
+    Replace(Box<dyn Scene>, Transition),
+    /// Remove every scene and stop
+    Quit,
+}
+
+impl Command {
+    pub fn push<S: Scene + 'static>(scene: S, transition: Transition) -> Self {
+        Command::Push(Box::new(scene), transition)
+    }
+
+    pub fn replace<S: Scene + 'static>(scene: S, transition: Transition) -> Self {
+        Command::Replace(Box::new(scene), transition)
+    }
+}
+
+/// A transition that is playing, with what was on screen before it started
+struct Active {
+    transition: Transition,
+    elapsed: f64,
+    /// Scenes from the stack that were on screen before, counting from the bottom
+    below: usize,
+    /// A scene that was removed from the stack but is still drawn until the
+    /// transition ends
+    outgoing: Option<Box<dyn Scene>>,
+}
+
+/// Scenes stacked on top of each other, with transitions between them
+///
+/// # Example
+/// ```
+/// use clige::core::{buffer::{Buffer, PixelBuffer}, data::Pixel};
+/// use clige::scene::{Command, Scene, SceneStack, Transition};
+///
+/// struct Title;
+/// struct Level(f64);
+///
+/// impl Scene for Title {
+///     fn update(&mut self, _delta: f64) -> Command {
+///         Command::replace(Level(0.), Transition::fade(0.5))
+///     }
+///
+///     fn render(&mut self, buffer: &mut PixelBuffer) {
+///         buffer.set(0, 0, Pixel::from('T')).unwrap();
+///     }
+/// }
+///
+/// impl Scene for Level {
+///     fn update(&mut self, delta: f64) -> Command {
+///         self.0 += delta;
+///         Command::None
+///     }
+///
+///     fn render(&mut self, buffer: &mut PixelBuffer) {
+///         buffer.set(0, 0, Pixel::from('L')).unwrap();
+///     }
+/// }
+///
+/// let mut scenes = SceneStack::new(Title);
+/// let mut screen = PixelBuffer::new(10, 5);
+///
+/// scenes.update(0.1);
+/// assert!(scenes.transitioning());
+///
+/// scenes.update(0.5);
+/// scenes.render(&mut screen);
+/// assert_eq!(screen.get(0, 0).map(|p| p.value), Some('L'));
+/// ```
+pub struct SceneStack {
+    scenes: Vec<Box<dyn Scene>>,
+    active: Option<Active>,
+    from: PixelBuffer,
+    to: PixelBuffer,
+}
+
+impl SceneStack {
+    /// Create a stack starting on `scene`
+    pub fn new<S: Scene + 'static>(scene: S) -> Self {
+        let mut scene: Box<dyn Scene> = Box::new(scene);
+        scene.enter();
+        SceneStack {
+            scenes: vec![scene],
+            active: None,
+            from: PixelBuffer::new(0, 0),
+            to: PixelBuffer::new(0, 0),
+        }
+    }
+
+    /// Whether any scenes are left. The game should stop once this is `false`
+    pub fn is_running(&self) -> bool {
+        !self.scenes.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.scenes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.scenes.is_empty()
+    }
+
+    /// Whether a transition is playing. Scenes aren't updated and don't handle input
+    /// until it ends
+    pub fn transitioning(&self) -> bool {
+        self.active.is_some()
+    }
+
+    /// Pause the top scene and put `scene` on top of it
+    pub fn push<S: Scene + 'static>(&mut self, scene: S, transition: Transition) {
+        self.apply(Command::push(scene, transition));
+    }
+
+    /// Remove the top scene and resume the one below
+    pub fn pop(&mut self, transition: Transition) {
+        self.apply(Command::Pop(transition));
+    }
+
+    /// Swap the top scene for `scene`
+    pub fn replace<S: Scene + 'static>(&mut self, scene: S, transition: Transition) {
+        self.apply(Command::replace(scene, transition));
+    }
+
+    /// Carry out a command, finishing any transition that is still playing first
+    pub fn apply(&mut self, command: Command) {
+        let (transition, below, outgoing) = match command {
+            Command::None => return,
+            Command::Push(mut scene, transition) => {
+                if let Some(top) = self.scenes.last_mut() {
+                    top.pause();
+                }
+                scene.enter();
+                self.scenes.push(scene);
+                (transition, self.scenes.len() - 1, None)
+            }
+            Command::Pop(transition) => {
+                let Some(mut top) = self.scenes.pop() else {
+                    return;
+                };
+                top.exit();
+                if let Some(below) = self.scenes.last_mut() {
+                    below.resume();
+                }
+                (transition, self.scenes.len(), Some(top))
+            }
+            Command::Replace(mut scene, transition) => {
+                let outgoing = self.scenes.pop().map(|mut top| {
+                    top.exit();
+                    top
+                });
+                scene.enter();
+                self.scenes.push(scene);
+                (transition, self.scenes.len() - 1, outgoing)
+            }
+            Command::Quit => {
+                while let Some(mut scene) = self.scenes.pop() {
+                    scene.exit();
+                }
+                self.active = None;
+                return;
+            }
+        };
+
+        self.active = (transition.duration() > 0.).then_some(Active {
+            transition,
+            elapsed: 0.,
+            below,
+            outgoing,
+        });
+    }
+
+    /// Pass an input event to the top scene
+    pub fn handle(&mut self, event: &Event) {
+        if self.active.is_some() {
+            return;
+        }
+        if let Some(command) = self.scenes.last_mut().map(|top| top.handle(event)) {
+            self.apply(command);
+        }
+    }
+
+    /// Advance a playing transition, or the top scene, by `delta` seconds
+    pub fn update(&mut self, delta: f64) {
+        if let Some(active) = self.active.as_mut() {
+            active.elapsed += delta;
+            if active.elapsed >= active.transition.duration() {
+                self.active = None;
+            }
+            return;
+        }
+
+        if let Some(command) = self.scenes.last_mut().map(|top| top.update(delta)) {
+            self.apply(command);
+        }
+    }
+
+    /// Draw the visible scenes into the buffer, blending between them while a
+    /// transition plays
+    pub fn render(&mut self, buffer: &mut PixelBuffer) {
+        let Some(active) = self.active.as_mut() else {
+            draw(self.scenes.iter_mut(), buffer);
+            return;
+        };
+
+        for scratch in [&mut self.from, &mut self.to] {
+            if scratch.width() != buffer.width() || scratch.height() != buffer.height() {
+                scratch.resize(buffer.width(), buffer.height());
+            }
+        }
+
+        let before = self.scenes[..active.below]
+            .iter_mut()
+            .chain(active.outgoing.as_mut());
+        draw(before, &mut self.from);
+        draw(self.scenes.iter_mut(), &mut self.to);
+
+        let progress = active.elapsed / active.transition.duration();
+        active
+            .transition
+            .blend(&self.from, &self.to, progress, buffer);
+    }
+}
+
+/// Clear the buffer and draw scenes from the bottom up, starting at the highest
+/// scene that isn't transparent
+fn draw<'s, I>(scenes: I, buffer: &mut PixelBuffer)
+where
+    I: DoubleEndedIterator<Item = &'s mut Box<dyn Scene>>,
+{
+    buffer.pixels.fill(Pixel::default());
+
+    let mut visible = Vec::new();
+    for scene in scenes.rev() {
+        let transparent = scene.transparent();
+        visible.push(scene);
+        if !transparent {
+            break;
+        }
+    }
+    for scene in visible.into_iter().rev() {
+        scene.render(buffer);
+    }
+}
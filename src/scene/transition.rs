@@ -0,0 +1,176 @@
+use crate::core::{
+    buffer::{Buffer, PixelBuffer},
+    color::{Rgb, Style},
+    data::Pixel,
+};
+
+/// Edge of the screen a wipe starts from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Edge {
+    #[default]
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+/// How the screen changes from one scene to another
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Transition {
+    /// Switch straight away
+    #[default]
+    Cut,
+    /// Fade the old scene into a solid color, then fade the new scene in from it
+    Fade { color: Rgb, duration: f64 },
+    /// Sweep the new scene across the old one, starting at an edge
+    Wipe { from: Edge, duration: f64 },
+    /// Swap cells from the old scene to the new one in a random order
+    Dissolve { duration: f64 },
+}
+
+impl Transition {
+    /// Fade through black
+    pub fn fade(duration: f64) -> Self {
+        Transition::Fade {
+            color: Rgb(0, 0, 0),
+            duration,
+        }
+    }
+
+    pub fn wipe(from: Edge, duration: f64) -> Self {
+        Transition::Wipe { from, duration }
+    }
+
+    pub fn dissolve(duration: f64) -> Self {
+        Transition::Dissolve { duration }
+    }
+
+    /// Seconds the transition takes
+    pub fn duration(&self) -> f64 {
+        match self {
+            Transition::Cut => 0.,
+            Transition::Fade { duration, .. }
+            | Transition::Wipe { duration, .. }
+            | Transition::Dissolve { duration } => duration.max(0.),
+        }
+    }
+
+    /// Draw the transition `progress` of the way from `from` to `to`, where `0.0`
+    /// is all of `from` and `1.0` is all of `to`
+    ///
+    /// The output is resized to match `to`. Cells missing from `from` are blank.
+    ///
+    /// # Example
+    /// ```
+    /// use clige::core::{buffer::{Buffer, PixelBuffer}, data::Pixel};
+    /// use clige::scene::transition::{Edge, Transition};
+    ///
+    /// let mut from = PixelBuffer::new(4, 1);
+    /// let mut to = PixelBuffer::new(4, 1);
+    /// for x in 0..4 {
+    ///     from.set(x, 0, Pixel::from('a')).unwrap();
+    ///     to.set(x, 0, Pixel::from('b')).unwrap();
+    /// }
+    ///
+    /// let mut output = PixelBuffer::new(4, 1);
+    /// Transition::wipe(Edge::Left, 1.).blend(&from, &to, 0.5, &mut output);
+    /// assert_eq!(output.pixels.iter().map(|p| p.value).collect::<String>(), "bbaa");
+    /// ```
+    pub fn blend(
+        &self,
+        from: &PixelBuffer,
+        to: &PixelBuffer,
+        progress: f64,
+        output: &mut PixelBuffer,
+    ) {
+        let progress = progress.clamp(0., 1.);
+        let (width, height) = (to.width(), to.height());
+        if output.width() != width || output.height() != height {
+            output.resize(width, height);
+        }
+
+        let blank = Pixel::default();
+        let old = |x: usize, y: usize| {
+            if x < from.width() && y < from.height() {
+                &from.pixels[y * from.width() + x]
+            } else {
+                &blank
+            }
+        };
+        let new = |x: usize, y: usize| &to.pixels[y * width + x];
+
+        if let Transition::Fade { color, .. } = self {
+            for y in 0..height {
+                for x in 0..width {
+                    output.pixels[y * width + x] = if progress < 0.5 {
+                        fade(old(x, y), color, progress * 2.)
+                    } else {
+                        fade(new(x, y), color, (1. - progress) * 2.)
+                    };
+                }
+            }
+            return;
+        }
+
+        for y in 0..height {
+            let mut x = 0;
+            while x < width {
+                let shows_new = self.shows_new(x, y, width, height, progress);
+                let pick = |x| if shows_new { new(x, y) } else { old(x, y) };
+
+                // Keep wide characters whole, taking both of their cells from one scene
+                let source = pick(x);
+                output.pixels[y * width + x] = match source.value {
+                    Pixel::CONTINUATION => Pixel::default(),
+                    _ => source.clone(),
+                };
+                if source.width() > 1 && x + 1 < width {
+                    output.pixels[y * width + x + 1] = pick(x + 1).clone();
+                    x += 1;
+                }
+                x += 1;
+            }
+        }
+    }
+
+    fn shows_new(&self, x: usize, y: usize, width: usize, height: usize, progress: f64) -> bool {
+        match self {
+            Transition::Cut => progress > 0.,
+            Transition::Fade { .. } => progress >= 0.5,
+            Transition::Wipe { from, .. } => {
+                let (position, size) = match from {
+                    Edge::Left => (x, width),
+                    Edge::Right => (width - 1 - x, width),
+                    Edge::Top => (y, height),
+                    Edge::Bottom => (height - 1 - y, height),
+                };
+                (position as f64) < progress * size as f64
+            }
+            Transition::Dissolve { .. } => threshold(x, y) < progress,
+        }
+    }
+}
+
+/// Blend a pixel's colors towards `color` by `t`. Cells using the terminal's
+/// default colors are treated as light gray on black
+fn fade(pixel: &Pixel, color: &Rgb, t: f64) -> Pixel {
+    let (foreground, background) = pixel.color.to_rgb();
+    let foreground = foreground.unwrap_or(Rgb(229, 229, 229)).lerp(color, t);
+    let background = background.unwrap_or(Rgb(0, 0, 0)).lerp(color, t);
+
+    Pixel::new(
+        pixel.value,
+        Style::new().fg(foreground).bg(background).color(),
+    )
+}
+
+/// A fixed, evenly spread value from `0.0` to `1.0` for each cell, so dissolves
+/// look random but never flicker
+fn threshold(x: usize, y: usize) -> f64 {
+    let mut hash = (x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (y as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+    hash ^= hash >> 31;
+    hash = hash.wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    hash ^= hash >> 29;
+    (hash >> 11) as f64 / (1u64 << 53) as f64
+}
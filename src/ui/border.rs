@@ -0,0 +1,99 @@
+use super::put;
+use crate::core::{
+    buffer::PixelBuffer,
+    color::Color,
+    data::{Pixel, Rect},
+};
+
+/// Line style used to draw the edge of an element
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Border {
+    #[default]
+    None,
+    Single,
+    Double,
+    Rounded,
+    Heavy,
+    Ascii,
+}
+
+/// Characters that make up a border
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbols {
+    pub top_left: char,
+    pub top_right: char,
+    pub bottom_left: char,
+    pub bottom_right: char,
+    pub horizontal: char,
+    pub vertical: char,
+}
+
+impl Symbols {
+    /// Ordered as: top left, top right, bottom left, bottom right, horizontal, vertical
+    const fn new(symbols: [char; 6]) -> Self {
+        Symbols {
+            top_left: symbols[0],
+            top_right: symbols[1],
+            bottom_left: symbols[2],
+            bottom_right: symbols[3],
+            horizontal: symbols[4],
+            vertical: symbols[5],
+        }
+    }
+}
+
+impl Border {
+    pub fn symbols(&self) -> Option<Symbols> {
+        match self {
+            Border::None => None,
+            Border::Single => Some(Symbols::new(['┌', '┐', '└', '┘', '─', '│'])),
+            Border::Double => Some(Symbols::new(['╔', '╗', '╚', '╝', '═', '║'])),
+            Border::Rounded => Some(Symbols::new(['╭', '╮', '╰', '╯', '─', '│'])),
+            Border::Heavy => Some(Symbols::new(['┏', '┓', '┗', '┛', '━', '┃'])),
+            Border::Ascii => Some(Symbols::new(['+', '+', '+', '+', '-', '|'])),
+        }
+    }
+
+    /// Number of cells the border takes up on each side
+    pub fn thickness(&self) -> usize {
+        match self {
+            Border::None => 0,
+            _ => 1,
+        }
+    }
+
+    /// The area left inside of the border
+    pub fn inner(&self, area: Rect) -> Rect {
+        area.shrink(self.thickness())
+    }
+
+    /// Draw the border along the edge of the area
+    pub fn render(&self, buffer: &mut PixelBuffer, area: Rect, color: &Color) {
+        let symbols = match self.symbols() {
+            Some(symbols) => symbols,
+            None => return,
+        };
+
+        if area.width() == 0 || area.height() == 0 {
+            return;
+        }
+
+        let (right, bottom) = (area.right - 1, area.bottom - 1);
+        let mut draw = |x, y, value| put(buffer, &area, x, y, Pixel::new(value, color.clone()));
+
+        for x in area.left + 1..right {
+            draw(x, area.top, symbols.horizontal);
+            draw(x, bottom, symbols.horizontal);
+        }
+
+        for y in area.top + 1..bottom {
+            draw(area.left, y, symbols.vertical);
+            draw(right, y, symbols.vertical);
+        }
+
+        draw(area.left, area.top, symbols.top_left);
+        draw(right, area.top, symbols.top_right);
+        draw(area.left, bottom, symbols.bottom_left);
+        draw(right, bottom, symbols.bottom_right);
+    }
+}
@@ -0,0 +1,178 @@
+use super::{
+    border::Border,
+    fill, is_activate,
+    text::{Overflow, Span, Text, Wrap},
+    theme::Theme,
+    Align, Click, Interactive, State, Styles, VAlign, Widget,
+};
+use crate::core::{
+    buffer::PixelBuffer,
+    color::Color,
+    data::{Pixel, Rect},
+    event::Event,
+};
+
+/// Message sent when a [`Button`] is activated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pressed;
+
+/// A clickable button with a centered label
+///
+/// The button is activated with `Enter` or `Space` while it is focused, or by
+/// pressing and releasing the left mouse button over it. Activation calls the
+/// `on_press` callback and returns [`Pressed`] from [`Interactive::handle`].
+///
+/// # Example
+/// ```
+/// use clige::core::{data::Rect, event::Key};
+/// use clige::ui::{Button, Interactive};
+///
+/// let mut button = Button::new("Start").on_press(|| println!("Starting"));
+/// button.set_focused(true);
+/// assert!(button.handle(&Key::Enter.into(), Rect::from([0, 0, 9, 3])).is_some());
+/// ```
+pub struct Button {
+    pub label: String,
+    border: Border,
+    styles: Styles,
+    on_press: Option<Box<dyn FnMut()>>,
+    click: Click,
+    /// Activated from the keyboard. Terminals don't report key releases, so this
+    /// only lasts until the next event
+    activated: bool,
+    disabled: bool,
+    focused: bool,
+}
+
+impl Button {
+    pub fn new<S: Into<String>>(label: S) -> Self {
+        Button {
+            label: label.into(),
+            border: Border::Single,
+            styles: Styles::default(),
+            on_press: None,
+            click: Click::default(),
+            activated: false,
+            disabled: false,
+            focused: false,
+        }
+    }
+
+    pub fn border(self, border: Border) -> Self {
+        Button { border, ..self }
+    }
+
+    /// Set the color used while the button is in the given state
+    pub fn style(mut self, state: State, color: Color) -> Self {
+        self.styles.set(state, color);
+        self
+    }
+
+    /// Take the colors for each state from a theme, using the `button` overrides
+    pub fn themed(self, theme: &Theme) -> Self {
+        Button {
+            styles: self.styles.themed(theme.states("button")),
+            ..self
+        }
+    }
+
+    /// Set the callback that is run each time the button is activated
+    pub fn on_press<F: FnMut() + 'static>(self, callback: F) -> Self {
+        Button {
+            on_press: Some(Box::new(callback)),
+            ..self
+        }
+    }
+
+    pub fn disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Disabling the button also clears any hover, press, or focus
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+        if disabled {
+            self.focused = false;
+            self.click = Click::default();
+            self.activated = false;
+        }
+    }
+
+    /// The state used to pick the button's color
+    ///
+    /// When multiple apply the highest priority wins: disabled, pressed,
+    /// hovered, then focused.
+    pub fn state(&self) -> State {
+        if self.disabled {
+            State::Disabled
+        } else if self.activated || self.click.pressed {
+            State::Pressed
+        } else if self.click.hovered {
+            State::Hovered
+        } else if self.focused {
+            State::Focused
+        } else {
+            State::Normal
+        }
+    }
+
+    /// Activate the button as if it was clicked
+    pub fn press(&mut self) -> Option<Pressed> {
+        if self.disabled {
+            return None;
+        }
+
+        if let Some(callback) = self.on_press.as_mut() {
+            callback();
+        }
+        Some(Pressed)
+    }
+}
+
+impl Widget for Button {
+    fn render(&self, buffer: &mut PixelBuffer, area: Rect) {
+        let color = self.styles.get(&self.state()).cloned().unwrap_or_default();
+
+        fill(buffer, &area, Pixel::new(' ', color.clone()));
+        self.border.render(buffer, area, &color);
+
+        Text::new(Span::new(self.label.as_str(), color))
+            .wrap(Wrap::None)
+            .align(Align::Center)
+            .valign(VAlign::Middle)
+            .overflow(Overflow::Ellipsis)
+            .render(buffer, self.border.inner(area));
+    }
+}
+
+impl Interactive for Button {
+    type Message = Pressed;
+
+    fn handle(&mut self, event: &Event, area: Rect) -> Option<Pressed> {
+        if self.disabled {
+            return None;
+        }
+
+        self.activated = false;
+        if self.click.update(event, &area) {
+            return self.press();
+        }
+        if self.focused && is_activate(event) {
+            self.activated = true;
+            return self.press();
+        }
+        None
+    }
+
+    fn focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused && !self.disabled;
+    }
+
+    fn focusable(&self) -> bool {
+        !self.disabled
+    }
+}
@@ -0,0 +1,338 @@
+use std::hash::Hash;
+
+use super::Interactive;
+use crate::core::{
+    data::Rect,
+    event::{Event, Key, MouseButton, MouseKind},
+};
+
+/// Direction used for arrow key navigation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// How elements without an explicit index are ordered when tabbing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Order {
+    /// The order the elements were added in
+    #[default]
+    Added,
+    /// Top to bottom then left to right, by each element's area
+    Reading,
+}
+
+/// Sent when focus moves from one element to another
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusChange<K> {
+    pub from: Option<K>,
+    pub to: Option<K>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry<K> {
+    key: K,
+    area: Rect,
+    index: Option<usize>,
+    enabled: bool,
+    scope: usize,
+}
+
+/// Tracks which element has keyboard focus and moves it between elements
+///
+/// Elements are identified by a key chosen by the caller and registered with the
+/// area they were rendered in. `Tab` and `Shift + Tab` cycle through them, elements
+/// with an explicit index coming first in index order. Arrow keys can optionally
+/// move focus to the nearest element in that direction.
+///
+/// Scopes limit navigation to a group of elements, such as the contents of a modal
+/// dialog. Elements added after [`FocusManager::push_scope`] belong to the new scope,
+/// and popping it returns focus to where it was before.
+///
+/// # Example
+/// ```
+/// use clige::core::{data::Rect, event::Key};
+/// use clige::ui::focus::{FocusChange, FocusManager};
+///
+/// let mut focus = FocusManager::new();
+/// focus.add("name", Rect::from([0, 0, 20, 1]));
+/// focus.add("start", Rect::from([0, 2, 10, 3]));
+///
+/// assert_eq!(
+///     focus.handle(&Key::Tab.into()),
+///     Some(FocusChange { from: Some("name"), to: Some("start") })
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct FocusManager<K> {
+    entries: Vec<Entry<K>>,
+    /// Scope ids from the bottom to the top, with the key that was focused when each was pushed
+    scopes: Vec<(usize, Option<K>)>,
+    next_scope: usize,
+    focused: Option<K>,
+    order: Order,
+    arrows: bool,
+}
+
+impl<K: Copy + Eq + Hash> Default for FocusManager<K> {
+    fn default() -> Self {
+        FocusManager::new()
+    }
+}
+
+impl<K: Copy + Eq + Hash> FocusManager<K> {
+    pub fn new() -> Self {
+        FocusManager {
+            entries: Vec::new(),
+            scopes: vec![(0, None)],
+            next_scope: 1,
+            focused: None,
+            order: Order::Added,
+            arrows: false,
+        }
+    }
+
+    pub fn order(self, order: Order) -> Self {
+        FocusManager { order, ..self }
+    }
+
+    /// Let the arrow keys move focus to the nearest element in that direction
+    pub fn arrows(self, arrows: bool) -> Self {
+        FocusManager { arrows, ..self }
+    }
+
+    fn scope(&self) -> usize {
+        self.scopes.last().map_or(0, |(scope, _)| *scope)
+    }
+
+    /// Add an element to the current scope, or update its area if it already exists
+    ///
+    /// The first element added to a scope is focused.
+    pub fn add(&mut self, key: K, area: Rect) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.key == key) {
+            entry.area = area;
+            return;
+        }
+
+        self.entries.push(Entry {
+            key,
+            area,
+            index: None,
+            enabled: true,
+            scope: self.scope(),
+        });
+
+        if self.focused.is_none() {
+            self.focused = Some(key);
+        }
+    }
+
+    /// Add an element with an explicit position in the tab order
+    pub fn add_indexed(&mut self, key: K, area: Rect, index: usize) {
+        self.add(key, area);
+        self.set_index(key, Some(index));
+    }
+
+    pub fn set_index(&mut self, key: K, index: Option<usize>) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.key == key) {
+            entry.index = index;
+        }
+    }
+
+    /// Update where an element was rendered
+    pub fn set_area(&mut self, key: K, area: Rect) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.key == key) {
+            entry.area = area;
+        }
+    }
+
+    /// Disabled elements are skipped. Disabling the focused element moves focus on
+    pub fn set_enabled(&mut self, key: K, enabled: bool) -> Option<FocusChange<K>> {
+        let change = match !enabled && self.focused == Some(key) {
+            true => self.focus_next(),
+            false => None,
+        };
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.key == key) {
+            entry.enabled = enabled;
+        }
+
+        if !enabled && self.focused == Some(key) {
+            return self.set(None);
+        }
+        change
+    }
+
+    /// Remove an element. Removing the focused element moves focus on
+    pub fn remove(&mut self, key: K) -> Option<FocusChange<K>> {
+        let change = match self.focused == Some(key) {
+            true => self.focus_next(),
+            false => None,
+        };
+        self.entries.retain(|e| e.key != key);
+
+        if self.focused == Some(key) {
+            return self.set(None);
+        }
+        change
+    }
+
+    /// Start a new scope. Navigation is limited to the elements added until it is popped
+    pub fn push_scope(&mut self) {
+        self.scopes.push((self.next_scope, self.focused));
+        self.next_scope += 1;
+        self.focused = None;
+    }
+
+    /// Remove the current scope and its elements, and restore the previous focus
+    pub fn pop_scope(&mut self) -> Option<FocusChange<K>> {
+        if self.scopes.len() == 1 {
+            return None;
+        }
+
+        let (scope, previous) = self.scopes.pop().unwrap();
+        self.entries.retain(|e| e.scope != scope);
+        self.set(previous)
+    }
+
+    pub fn focused(&self) -> Option<K> {
+        self.focused
+    }
+
+    pub fn is_focused(&self, key: K) -> bool {
+        self.focused == Some(key)
+    }
+
+    /// Focus an element in the current scope
+    pub fn focus(&mut self, key: K) -> Option<FocusChange<K>> {
+        if !self.candidates().any(|e| e.key == key) {
+            return None;
+        }
+        self.set(Some(key))
+    }
+
+    fn set(&mut self, to: Option<K>) -> Option<FocusChange<K>> {
+        if self.focused == to {
+            return None;
+        }
+
+        let from = self.focused;
+        self.focused = to;
+        Some(FocusChange { from, to })
+    }
+
+    /// Enabled elements in the current scope
+    fn candidates(&self) -> impl Iterator<Item = &Entry<K>> {
+        let scope = self.scope();
+        self.entries
+            .iter()
+            .filter(move |e| e.enabled && e.scope == scope)
+    }
+
+    /// Keys in the current scope in tab order
+    pub fn tab_order(&self) -> Vec<K> {
+        let mut entries = self.candidates().enumerate().collect::<Vec<_>>();
+        entries.sort_by_key(|(added, entry)| {
+            let position = match self.order {
+                Order::Added => (0, *added),
+                Order::Reading => (entry.area.top, entry.area.left),
+            };
+            (entry.index.is_none(), entry.index, position)
+        });
+        entries.into_iter().map(|(_, entry)| entry.key).collect()
+    }
+
+    fn step(&mut self, forward: bool) -> Option<FocusChange<K>> {
+        let order = self.tab_order();
+        if order.is_empty() {
+            return None;
+        }
+
+        let next = match self
+            .focused
+            .and_then(|f| order.iter().position(|k| *k == f))
+        {
+            Some(current) if forward => (current + 1) % order.len(),
+            Some(current) => (current + order.len() - 1) % order.len(),
+            None if forward => 0,
+            None => order.len() - 1,
+        };
+        self.set(Some(order[next]))
+    }
+
+    /// Focus the next element in tab order, wrapping around at the end
+    pub fn focus_next(&mut self) -> Option<FocusChange<K>> {
+        self.step(true)
+    }
+
+    /// Focus the previous element in tab order, wrapping around at the start
+    pub fn focus_previous(&mut self) -> Option<FocusChange<K>> {
+        self.step(false)
+    }
+
+    /// Focus the nearest element in a direction from the focused element
+    ///
+    /// Elements are compared by the centers of their areas. Distance across the
+    /// direction of travel counts double, so elements that are lined up are preferred.
+    pub fn navigate(&mut self, direction: Direction) -> Option<FocusChange<K>> {
+        let current = match self.focused {
+            Some(focused) => self.entries.iter().find(|e| e.key == focused)?,
+            None => return self.focus_next(),
+        };
+
+        let center = |r: &Rect| ((r.left + r.right) as isize, (r.top + r.bottom) as isize);
+        let (x, y) = center(&current.area);
+
+        let target = self
+            .candidates()
+            .filter(|e| e.key != current.key)
+            .filter_map(|e| {
+                let (ex, ey) = center(&e.area);
+                let (along, across) = match direction {
+                    Direction::Up => (y - ey, ex - x),
+                    Direction::Down => (ey - y, ex - x),
+                    Direction::Left => (x - ex, ey - y),
+                    Direction::Right => (ex - x, ey - y),
+                };
+                (along > 0).then_some((along + across.abs() * 2, e.key))
+            })
+            .min_by_key(|(score, _)| *score)
+            .map(|(_, key)| key)?;
+
+        self.set(Some(target))
+    }
+
+    /// Move focus in response to input
+    ///
+    /// Handles `Tab`, `Shift + Tab`, the arrow keys when enabled, and clicking on an
+    /// element. Returns a change when focus moved.
+    pub fn handle(&mut self, event: &Event) -> Option<FocusChange<K>> {
+        match event {
+            Event::Key(key) => match key.key {
+                Key::Tab if key.modifiers.shift => self.focus_previous(),
+                Key::Tab => self.focus_next(),
+                Key::BackTab => self.focus_previous(),
+                Key::Up if self.arrows => self.navigate(Direction::Up),
+                Key::Down if self.arrows => self.navigate(Direction::Down),
+                Key::Left if self.arrows => self.navigate(Direction::Left),
+                Key::Right if self.arrows => self.navigate(Direction::Right),
+                _ => None,
+            },
+            Event::Mouse(mouse) if mouse.kind == MouseKind::Down(MouseButton::Left) => {
+                let key = self
+                    .candidates()
+                    .find(|e| e.area.contains(mouse.x, mouse.y))?
+                    .key;
+                self.set(Some(key))
+            }
+            _ => None,
+        }
+    }
+
+    /// Tell an element whether it has focus
+    pub fn apply<I: Interactive>(&self, key: K, element: &mut I) {
+        element.set_focused(self.is_focused(key));
+    }
+}
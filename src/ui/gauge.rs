@@ -0,0 +1,296 @@
+use super::{
+    draw_bar, put,
+    text::{Span, Text, Wrap},
+    theme::{Role, Theme},
+    Align, Orientation, VAlign, Widget,
+};
+use crate::core::{
+    buffer::PixelBuffer,
+    color::{Color, Context, Gradient},
+    data::{Pixel, Rect},
+};
+
+/// What a [`Gauge`] shows on top of its bar
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Label {
+    #[default]
+    None,
+    /// The value as a whole percentage
+    Percent,
+    Text(String),
+}
+
+/// A bar filled to a value between `0.0` and `1.0`, for progress and health bars
+///
+/// The bar is filled in eighths of a cell. An indeterminate gauge instead shows a
+/// block bouncing back and forth, moved along each time [`Gauge::tick`] is called.
+///
+/// # Example
+/// ```
+/// use clige::core::{buffer::{Buffer, PixelBuffer}, color::{Gradient, Rgb}, data::Rect};
+/// use clige::ui::{gauge::{Gauge, Label}, Widget};
+///
+/// let mut buffer = PixelBuffer::new(20, 1);
+/// Gauge::new(0.42)
+///     .gradient(Gradient::new(vec![(0.0, Rgb(200, 0, 0)), (1.0, Rgb(0, 200, 0))]))
+///     .label(Label::Percent)
+///     .render(&mut buffer, Rect::from([0, 0, 20, 1]));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gauge {
+    value: f64,
+    indeterminate: bool,
+    frame: usize,
+    orientation: Orientation,
+    label: Label,
+    color: Option<Color>,
+    gradient: Option<Gradient>,
+    track: Pixel,
+    label_color: Option<Color>,
+    /// Bar and label colors from the theme, used for any that aren't set directly
+    theme: (Color, Color),
+}
+
+impl Default for Gauge {
+    fn default() -> Self {
+        Gauge::new(0.)
+    }
+}
+
+impl Gauge {
+    pub fn new(value: f64) -> Self {
+        Gauge {
+            value: value.clamp(0., 1.),
+            indeterminate: false,
+            frame: 0,
+            orientation: Orientation::Horizontal,
+            label: Label::None,
+            color: None,
+            gradient: None,
+            track: Pixel::new(' ', Color::default()),
+            label_color: None,
+            theme: (Color::default(), Color::default()),
+        }
+    }
+
+    /// Create a gauge for work of unknown length
+    pub fn indeterminate() -> Self {
+        Gauge {
+            indeterminate: true,
+            ..Gauge::new(0.)
+        }
+    }
+
+    pub fn orientation(self, orientation: Orientation) -> Self {
+        Gauge {
+            orientation,
+            ..self
+        }
+    }
+
+    pub fn label(self, label: Label) -> Self {
+        Gauge { label, ..self }
+    }
+
+    pub fn label_color(self, label_color: Color) -> Self {
+        Gauge {
+            label_color: Some(label_color),
+            ..self
+        }
+    }
+
+    /// Color of the filled part of the bar
+    pub fn color(self, color: Color) -> Self {
+        Gauge {
+            color: Some(color),
+            ..self
+        }
+    }
+
+    /// Color the filled part of the bar by sampling the gradient at the current value
+    pub fn gradient(self, gradient: Gradient) -> Self {
+        Gauge {
+            gradient: Some(gradient),
+            ..self
+        }
+    }
+
+    /// Take the bar and label colors from a theme, using the `gauge` overrides
+    ///
+    /// Colors set with [`Gauge::color`] and [`Gauge::label_color`] are kept.
+    pub fn themed(self, theme: &Theme) -> Self {
+        Gauge {
+            theme: (
+                theme.color("gauge", Role::Primary),
+                theme.color("gauge", Role::Text),
+            ),
+            ..self
+        }
+    }
+
+    /// Set the pixel drawn in the unfilled part of the bar
+    pub fn track(self, track: Pixel) -> Self {
+        Gauge { track, ..self }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Set the value, clamped between `0.0` and `1.0`
+    pub fn set_value(&mut self, value: f64) {
+        self.value = value.clamp(0., 1.);
+    }
+
+    /// Set the value from an amount of work done out of a total
+    pub fn set_progress(&mut self, done: usize, total: usize) {
+        self.set_value(match total {
+            0 => 1.,
+            _ => done as f64 / total as f64,
+        });
+    }
+
+    /// Advance the animation of an indeterminate gauge by a frame
+    pub fn tick(&mut self) {
+        self.frame = self.frame.wrapping_add(1);
+    }
+
+    fn fill_color(&self) -> Color {
+        match self.gradient.as_ref() {
+            Some(gradient) => gradient.at(self.value).color(Context::Foreground),
+            None => self.color.clone().unwrap_or_else(|| self.theme.0.clone()),
+        }
+    }
+
+    /// Draw a block a quarter of the length of the bar that bounces between the ends
+    fn render_bounce(&self, buffer: &mut PixelBuffer, area: &Rect) {
+        let length = match self.orientation {
+            Orientation::Horizontal => area.width(),
+            Orientation::Vertical => area.height(),
+        };
+        let size = (length / 4).max(1);
+        let travel = length.saturating_sub(size);
+        let position = match travel {
+            0 => 0,
+            _ => {
+                let step = self.frame % (travel * 2);
+                step.min(travel * 2 - step)
+            }
+        };
+
+        let block = Pixel::new('█', self.fill_color());
+        for y in area.top..area.bottom {
+            for x in area.left..area.right {
+                let along = match self.orientation {
+                    Orientation::Horizontal => x - area.left,
+                    Orientation::Vertical => y - area.top,
+                };
+                let pixel = match along >= position && along < position + size {
+                    true => block.clone(),
+                    false => self.track.clone(),
+                };
+                put(buffer, area, x, y, pixel);
+            }
+        }
+    }
+}
+
+impl Widget for Gauge {
+    fn render(&self, buffer: &mut PixelBuffer, area: Rect) {
+        if self.indeterminate {
+            self.render_bounce(buffer, &area);
+        } else {
+            draw_bar(
+                buffer,
+                &area,
+                self.value,
+                self.orientation,
+                &self.fill_color(),
+                &self.track,
+            );
+        }
+
+        let label = match &self.label {
+            Label::None => return,
+            Label::Percent if self.indeterminate => return,
+            Label::Percent => format!("{}%", (self.value * 100.).round()),
+            Label::Text(text) => text.clone(),
+        };
+
+        let color = self
+            .label_color
+            .clone()
+            .unwrap_or_else(|| self.theme.1.clone());
+        Text::new(Span::new(label, color))
+            .wrap(Wrap::None)
+            .align(Align::Center)
+            .valign(VAlign::Middle)
+            .render(buffer, area);
+    }
+}
+
+/// Frames of a [`Spinner`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Frames {
+    #[default]
+    Braille,
+    Ascii,
+    Blocks,
+}
+
+impl Frames {
+    fn frames(&self) -> &'static [char] {
+        match self {
+            Frames::Braille => &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'],
+            Frames::Ascii => &['|', '/', '-', '\\'],
+            Frames::Blocks => &['▖', '▘', '▝', '▗'],
+        }
+    }
+}
+
+/// A single cell animation followed by an optional label for work of unknown length
+///
+/// Call [`Spinner::tick`] once per frame to animate it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Spinner {
+    frames: Frames,
+    frame: usize,
+    label: String,
+    color: Color,
+}
+
+impl Spinner {
+    pub fn new<S: Into<String>>(label: S) -> Self {
+        Spinner {
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn frames(self, frames: Frames) -> Self {
+        Spinner { frames, ..self }
+    }
+
+    pub fn color(self, color: Color) -> Self {
+        Spinner { color, ..self }
+    }
+
+    /// Advance the animation by a frame
+    pub fn tick(&mut self) {
+        self.frame = (self.frame + 1) % self.frames.frames().len();
+    }
+}
+
+impl Widget for Spinner {
+    fn render(&self, buffer: &mut PixelBuffer, area: Rect) {
+        let frame = self.frames.frames()[self.frame % self.frames.frames().len()];
+        let content = match self.label.is_empty() {
+            true => frame.to_string(),
+            false => format!("{} {}", frame, self.label),
+        };
+
+        Text::new(Span::new(content, self.color.clone()))
+            .wrap(Wrap::None)
+            .render(buffer, area);
+    }
+}
@@ -0,0 +1,572 @@
+use super::{
+    put,
+    theme::{Role, Theme},
+    Interactive, State, Styles, Widget,
+};
+use crate::core::{
+    buffer::PixelBuffer,
+    color::Color,
+    data::{char_width, Pixel, Rect},
+    event::{Event, Key, KeyEvent, MouseButton, MouseKind},
+};
+
+/// Message sent by a [`TextInput`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    /// The text was edited, holding the new text
+    Changed(String),
+    /// `Enter` was pressed in a single line input, or `Ctrl + Enter` in a multi-line one
+    Submit(String),
+}
+
+fn is_word(value: char) -> bool {
+    value.is_alphanumeric() || value == '_'
+}
+
+/// An editable text field
+///
+/// Supports cursor movement with the arrow keys, `Home` and `End`, moving by word
+/// with `Ctrl`, selecting with `Shift` or by dragging the mouse, and selecting
+/// everything with `Ctrl + a`. Content that does not fit is scrolled so the
+/// cursor stays visible.
+///
+/// # Example
+/// ```
+/// use clige::core::{data::Rect, event::Key};
+/// use clige::ui::{input::{Message, TextInput}, Interactive};
+///
+/// let area = Rect::from([0, 0, 20, 1]);
+/// let mut name = TextInput::new().placeholder("Name").max_length(12);
+/// name.set_focused(true);
+/// for c in "Fox".chars() {
+///     name.handle(&Key::Char(c).into(), area);
+/// }
+/// assert_eq!(
+///     name.handle(&Key::Enter.into(), area),
+///     Some(Message::Submit("Fox".to_string()))
+/// );
+/// ```
+pub struct TextInput {
+    chars: Vec<char>,
+    cursor: usize,
+    /// Other end of the selection; the selection runs between this and the cursor
+    anchor: Option<usize>,
+    /// Columns and rows scrolled past
+    scroll: (usize, usize),
+    multiline: bool,
+    placeholder: String,
+    max_length: Option<usize>,
+    mask: Option<char>,
+    styles: Styles,
+    placeholder_color: Color,
+    cursor_color: Color,
+    selection_color: Color,
+    selecting: bool,
+    disabled: bool,
+    focused: bool,
+}
+
+impl Default for TextInput {
+    fn default() -> Self {
+        TextInput::new()
+    }
+}
+
+impl TextInput {
+    /// Create an empty single line input
+    pub fn new() -> Self {
+        TextInput {
+            chars: Vec::new(),
+            cursor: 0,
+            anchor: None,
+            scroll: (0, 0),
+            multiline: false,
+            placeholder: String::new(),
+            max_length: None,
+            mask: None,
+            styles: Styles::default(),
+            placeholder_color: Color::new("90", "49"),
+            cursor_color: Color::new("30", "47"),
+            selection_color: Color::new("30", "46"),
+            selecting: false,
+            disabled: false,
+            focused: false,
+        }
+    }
+
+    /// Allow multiple lines of text. `Enter` inserts a new line
+    pub fn multiline(self, multiline: bool) -> Self {
+        TextInput { multiline, ..self }
+    }
+
+    /// Text shown while the input is empty
+    pub fn placeholder<S: Into<String>>(self, placeholder: S) -> Self {
+        TextInput {
+            placeholder: placeholder.into(),
+            ..self
+        }
+    }
+
+    /// Maximum number of characters the input will accept
+    pub fn max_length(self, max_length: usize) -> Self {
+        TextInput {
+            max_length: Some(max_length),
+            ..self
+        }
+    }
+
+    /// Draw every character as `mask`, for passwords
+    pub fn mask(self, mask: char) -> Self {
+        TextInput {
+            mask: Some(mask),
+            ..self
+        }
+    }
+
+    /// Set the color of the text while the input is in the given state
+    pub fn style(mut self, state: State, color: Color) -> Self {
+        self.styles.set(state, color);
+        self
+    }
+
+    /// Take the colors for each state from a theme, using the `input` overrides
+    pub fn themed(self, theme: &Theme) -> Self {
+        TextInput {
+            styles: self.styles.themed(theme.states("input")),
+            placeholder_color: theme.color("input", Role::Muted),
+            cursor_color: theme.color("input", Role::Pressed),
+            selection_color: theme.color("input", Role::Selection),
+            ..self
+        }
+    }
+
+    pub fn value(&self) -> String {
+        self.chars.iter().collect()
+    }
+
+    /// Replace the text and move the cursor to the end without emitting a change
+    pub fn set_value<S: AsRef<str>>(&mut self, value: S) {
+        self.chars = value
+            .as_ref()
+            .chars()
+            .filter(|c| self.multiline || *c != '\n')
+            .collect();
+        if let Some(max) = self.max_length {
+            self.chars.truncate(max);
+        }
+        self.cursor = self.chars.len();
+        self.anchor = None;
+    }
+
+    /// Index of the cursor in characters
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Range of characters that are selected, if any
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        match self.anchor {
+            Some(anchor) if anchor != self.cursor => {
+                Some((anchor.min(self.cursor), anchor.max(self.cursor)))
+            }
+            _ => None,
+        }
+    }
+
+    /// The selected text, if any
+    pub fn selected(&self) -> Option<String> {
+        self.selection()
+            .map(|(start, end)| self.chars[start..end].iter().collect())
+    }
+
+    pub fn select_all(&mut self) {
+        self.anchor = Some(0);
+        self.cursor = self.chars.len();
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+        if disabled {
+            self.focused = false;
+            self.selecting = false;
+        }
+    }
+
+    /// Insert text at the cursor, replacing the selection
+    ///
+    /// Text past the maximum length is dropped. Returns whether anything changed.
+    pub fn insert(&mut self, text: &str) -> bool {
+        let removed = self.delete_selection();
+
+        let room = self
+            .max_length
+            .map_or(usize::MAX, |max| max.saturating_sub(self.chars.len()));
+        let text = text
+            .chars()
+            .filter(|c| self.multiline || *c != '\n')
+            .take(room)
+            .collect::<Vec<_>>();
+
+        let inserted = !text.is_empty();
+        let count = text.len();
+        self.chars.splice(self.cursor..self.cursor, text);
+        self.cursor += count;
+        removed || inserted
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        let selection = self.selection();
+        self.anchor = None;
+        match selection {
+            Some((start, end)) => {
+                self.chars.drain(start..end);
+                self.cursor = start;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Delete from the cursor to `to`, or the selection when there is one
+    fn delete_to(&mut self, to: usize) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+
+        let (start, end) = (self.cursor.min(to), self.cursor.max(to));
+        self.chars.drain(start..end);
+        self.cursor = start;
+        start != end
+    }
+
+    /// Move the cursor, extending the selection when `select` is true
+    fn move_to(&mut self, to: usize, select: bool) {
+        if select {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = to.min(self.chars.len());
+    }
+
+    fn previous_word(&self) -> usize {
+        let mut index = self.cursor;
+        while index > 0 && !is_word(self.chars[index - 1]) {
+            index -= 1;
+        }
+        while index > 0 && is_word(self.chars[index - 1]) {
+            index -= 1;
+        }
+        index
+    }
+
+    fn next_word(&self) -> usize {
+        let mut index = self.cursor;
+        while index < self.chars.len() && !is_word(self.chars[index]) {
+            index += 1;
+        }
+        while index < self.chars.len() && is_word(self.chars[index]) {
+            index += 1;
+        }
+        index
+    }
+
+    /// Start index of each line
+    fn line_starts(&self) -> Vec<usize> {
+        std::iter::once(0)
+            .chain(
+                self.chars
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, c)| **c == '\n')
+                    .map(|(i, _)| i + 1),
+            )
+            .collect()
+    }
+
+    /// Line and character column of an index
+    fn position(&self, index: usize) -> (usize, usize) {
+        let starts = self.line_starts();
+        let line = starts.partition_point(|start| *start <= index) - 1;
+        (line, index - starts[line])
+    }
+
+    fn line_end(&self, start: usize) -> usize {
+        self.chars[start..]
+            .iter()
+            .position(|c| *c == '\n')
+            .map_or(self.chars.len(), |offset| start + offset)
+    }
+
+    /// The character shown for a value
+    fn display(&self, value: char) -> char {
+        match self.mask {
+            Some(mask) if value != '\n' => mask,
+            _ => value,
+        }
+    }
+
+    fn display_width(&self, chars: &[char]) -> usize {
+        chars.iter().map(|c| char_width(self.display(*c))).sum()
+    }
+
+    /// Index of the character at a display column in a line
+    fn index_at_column(&self, start: usize, column: usize) -> usize {
+        let end = self.line_end(start);
+        let mut used = 0;
+        for index in start..end {
+            let width = char_width(self.display(self.chars[index]));
+            if used + width > column {
+                return index;
+            }
+            used += width;
+        }
+        end
+    }
+
+    /// Move the cursor by `lines`, keeping the same display column where possible
+    fn move_lines(&mut self, lines: isize, select: bool) {
+        let starts = self.line_starts();
+        let (line, _) = self.position(self.cursor);
+        let column = self.display_width(&self.chars[starts[line]..self.cursor]);
+
+        let target = line as isize + lines;
+        let to = if target < 0 {
+            0
+        } else if target as usize >= starts.len() {
+            self.chars.len()
+        } else {
+            self.index_at_column(starts[target as usize], column)
+        };
+        self.move_to(to, select);
+    }
+
+    /// Scroll offset that keeps the cursor inside of the area
+    fn visible_scroll(&self, area: &Rect) -> (usize, usize) {
+        let (line, _) = self.position(self.cursor);
+        let start = self.line_starts()[line];
+        let column = self.display_width(&self.chars[start..self.cursor]);
+        let (width, height) = (area.width().max(1), area.height().max(1));
+
+        let (mut x, mut y) = self.scroll;
+        if column < x {
+            x = column;
+        } else if column >= x + width {
+            x = column + 1 - width;
+        }
+
+        if !self.multiline || line < y {
+            y = line;
+        } else if line >= y + height {
+            y = line + 1 - height;
+        }
+        (x, y)
+    }
+
+    /// Index of the character under a position in the area
+    fn index_at(&self, area: &Rect, x: usize, y: usize) -> usize {
+        let starts = self.line_starts();
+        let row = y.saturating_sub(area.top) + self.scroll.1;
+        let start = starts[row.min(starts.len() - 1)];
+        self.index_at_column(start, x.saturating_sub(area.left) + self.scroll.0)
+    }
+
+    fn handle_key(&mut self, key: &KeyEvent) -> Option<Message> {
+        let select = key.modifiers.shift;
+        let ctrl = key.modifiers.ctrl;
+
+        let changed = match key.key {
+            Key::Char('a') if ctrl => {
+                self.select_all();
+                false
+            }
+            Key::Char(value) if !ctrl && !key.modifiers.alt => {
+                self.insert(value.encode_utf8(&mut [0; 4]))
+            }
+            Key::Enter if self.multiline && !ctrl => self.insert("\n"),
+            Key::Enter => return Some(Message::Submit(self.value())),
+            Key::Backspace if ctrl => self.delete_to(self.previous_word()),
+            Key::Backspace => self.delete_to(self.cursor.saturating_sub(1)),
+            Key::Delete if ctrl => self.delete_to(self.next_word()),
+            Key::Delete => self.delete_to((self.cursor + 1).min(self.chars.len())),
+            Key::Left => {
+                let to = match (ctrl, self.selection()) {
+                    (true, _) => self.previous_word(),
+                    (false, Some((start, _))) if !select => start,
+                    _ => self.cursor.saturating_sub(1),
+                };
+                self.move_to(to, select);
+                false
+            }
+            Key::Right => {
+                let to = match (ctrl, self.selection()) {
+                    (true, _) => self.next_word(),
+                    (false, Some((_, end))) if !select => end,
+                    _ => self.cursor + 1,
+                };
+                self.move_to(to, select);
+                false
+            }
+            Key::Up if self.multiline => {
+                self.move_lines(-1, select);
+                false
+            }
+            Key::Down if self.multiline => {
+                self.move_lines(1, select);
+                false
+            }
+            Key::Home if ctrl => {
+                self.move_to(0, select);
+                false
+            }
+            Key::End if ctrl => {
+                self.move_to(self.chars.len(), select);
+                false
+            }
+            Key::Home => {
+                let (line, _) = self.position(self.cursor);
+                self.move_to(self.line_starts()[line], select);
+                false
+            }
+            Key::End => {
+                let (line, _) = self.position(self.cursor);
+                self.move_to(self.line_end(self.line_starts()[line]), select);
+                false
+            }
+            _ => false,
+        };
+
+        changed.then(|| Message::Changed(self.value()))
+    }
+}
+
+impl Widget for TextInput {
+    fn render(&self, buffer: &mut PixelBuffer, area: Rect) {
+        let state = match (self.disabled, self.focused) {
+            (true, _) => State::Disabled,
+            (_, true) => State::Focused,
+            _ => State::Normal,
+        };
+        let color = self.styles.get(&state).cloned().unwrap_or_default();
+        let (scroll_x, scroll_y) = self.visible_scroll(&area);
+        let height = if self.multiline { area.height() } else { 1 };
+
+        for y in area.top..area.top + height.min(area.height()) {
+            for x in area.left..area.right {
+                put(buffer, &area, x, y, Pixel::new(' ', color.clone()));
+            }
+        }
+
+        if self.chars.is_empty() && !self.placeholder.is_empty() {
+            let mut x = area.left;
+            for value in self.placeholder.chars() {
+                put(
+                    buffer,
+                    &area,
+                    x,
+                    area.top,
+                    Pixel::new(value, self.placeholder_color.clone()),
+                );
+                x += char_width(value);
+            }
+        }
+
+        let selection = self.selection();
+        let starts = self.line_starts();
+        for (row, start) in starts.iter().skip(scroll_y).take(height).enumerate() {
+            let y = area.top + row;
+            let mut column = 0;
+
+            for index in *start..=self.line_end(*start) {
+                let cursor = self.focused && index == self.cursor;
+                let value = match self.chars.get(index) {
+                    Some('\n') | None => ' ',
+                    Some(value) => self.display(*value),
+                };
+                let width = char_width(value).max(1);
+
+                if index == self.chars.len() || self.chars[index] == '\n' {
+                    // Only the end of the line left to draw, which is where the cursor can sit
+                    if !cursor {
+                        break;
+                    }
+                }
+
+                let selected = selection.is_some_and(|(s, e)| index >= s && index < e);
+                let color = if cursor {
+                    self.cursor_color.clone()
+                } else if selected {
+                    self.selection_color.clone()
+                } else {
+                    color.clone()
+                };
+
+                if column >= scroll_x && column + width <= scroll_x + area.width() {
+                    let x = area.left + column - scroll_x;
+                    put(buffer, &area, x, y, Pixel::new(value, color.clone()));
+                    if width > 1 {
+                        put(
+                            buffer,
+                            &area,
+                            x + 1,
+                            y,
+                            Pixel::new(Pixel::CONTINUATION, color),
+                        );
+                    }
+                }
+                column += width;
+            }
+        }
+    }
+}
+
+impl Interactive for TextInput {
+    type Message = Message;
+
+    fn handle(&mut self, event: &Event, area: Rect) -> Option<Message> {
+        if self.disabled {
+            return None;
+        }
+
+        let message = match event {
+            Event::Key(key) if self.focused => self.handle_key(key),
+            Event::Mouse(mouse) => match mouse.kind {
+                MouseKind::Down(MouseButton::Left) if area.contains(mouse.x, mouse.y) => {
+                    let index = self.index_at(&area, mouse.x, mouse.y);
+                    self.move_to(index, mouse.modifiers.shift);
+                    self.selecting = true;
+                    None
+                }
+                MouseKind::Drag(MouseButton::Left) if self.selecting => {
+                    let index = self.index_at(&area, mouse.x, mouse.y);
+                    self.move_to(index, true);
+                    None
+                }
+                MouseKind::Up(MouseButton::Left) => {
+                    self.selecting = false;
+                    None
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+
+        self.scroll = self.visible_scroll(&area);
+        message
+    }
+
+    fn focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused && !self.disabled;
+        if !self.focused {
+            self.anchor = None;
+        }
+    }
+
+    fn focusable(&self) -> bool {
+        !self.disabled
+    }
+}
@@ -0,0 +1,509 @@
+use std::collections::BTreeSet;
+
+use super::{
+    put,
+    text::{Overflow, Span, Text, Wrap},
+    theme::Theme,
+    Interactive, State, Styles, Widget,
+};
+use crate::core::{
+    buffer::PixelBuffer,
+    color::Color,
+    data::{Pixel, Rect},
+    event::{Event, Key, MouseButton, MouseKind},
+};
+
+/// An entry in a [`List`]. Items with children open as a submenu
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Item {
+    pub label: String,
+    pub children: Vec<Item>,
+    pub disabled: bool,
+}
+
+impl Item {
+    pub fn new<S: Into<String>>(label: S) -> Self {
+        Item {
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Create an item that opens a submenu of `children`
+    pub fn submenu<S: Into<String>>(label: S, children: Vec<Item>) -> Self {
+        Item {
+            label: label.into(),
+            children,
+            disabled: false,
+        }
+    }
+
+    pub fn disabled(self, disabled: bool) -> Self {
+        Item { disabled, ..self }
+    }
+}
+
+impl<S: Into<String>> From<S> for Item {
+    fn from(value: S) -> Self {
+        Item::new(value)
+    }
+}
+
+/// Message sent by a [`List`]
+///
+/// Items are identified by their path: the index of the item at each level of
+/// submenus, starting from the top level.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    /// The highlighted item changed
+    Highlight(Vec<usize>),
+    /// An item without children was chosen
+    Activate(Vec<usize>),
+    /// An item was checked or unchecked in a multi-select list
+    Check(Vec<usize>, bool),
+}
+
+/// A submenu that was opened, remembering where the parent list was
+#[derive(Debug, Clone, Copy)]
+struct Level {
+    selected: usize,
+    offset: usize,
+}
+
+/// A scrollable list of items for menus and inventories
+///
+/// While focused, `Up` and `Down` move the highlight, `PageUp` and `PageDown` move
+/// by a page, and `Home` and `End` jump to the ends. `Enter` or `Right` opens a
+/// submenu and `Left`, `Backspace` or `Esc` goes back to the parent. In a
+/// multi-select list `Space` checks the highlighted item. A scrollbar is drawn on
+/// the right edge when the items don't fit.
+///
+/// # Example
+/// ```
+/// use clige::core::{data::Rect, event::Key};
+/// use clige::ui::{list::{Item, List, Message}, Interactive};
+///
+/// let area = Rect::from([0, 0, 20, 5]);
+/// let mut menu = List::new(vec![
+///     Item::new("Continue"),
+///     Item::submenu("Options", vec!["Audio".into(), "Video".into()]),
+///     Item::new("Quit"),
+/// ]);
+/// menu.set_focused(true);
+/// menu.handle(&Key::Down.into(), area);
+/// menu.handle(&Key::Enter.into(), area);
+/// menu.handle(&Key::Down.into(), area);
+/// assert_eq!(menu.handle(&Key::Enter.into(), area), Some(Message::Activate(vec![1, 1])));
+/// ```
+pub struct List {
+    items: Vec<Item>,
+    selected: usize,
+    offset: usize,
+    /// Parent levels of the open submenu, from the top level down
+    path: Vec<Level>,
+    multi: bool,
+    checked: BTreeSet<Vec<usize>>,
+    styles: Styles,
+    pressed: Option<usize>,
+    disabled: bool,
+    focused: bool,
+}
+
+impl List {
+    pub fn new<I: Into<Item>>(items: impl IntoIterator<Item = I>) -> Self {
+        let mut list = List {
+            items: items.into_iter().map(Into::into).collect(),
+            selected: 0,
+            offset: 0,
+            path: Vec::new(),
+            multi: false,
+            checked: BTreeSet::new(),
+            styles: Styles::default(),
+            pressed: None,
+            disabled: false,
+            focused: false,
+        };
+        list.selected = list.next_enabled(0, 1).unwrap_or(0);
+        list
+    }
+
+    /// Allow checking multiple items with `Space` or by clicking them
+    pub fn multi_select(self, multi: bool) -> Self {
+        List { multi, ..self }
+    }
+
+    /// Set the color used for the highlighted item while the list is in the given state
+    ///
+    /// Other items use the [`State::Normal`] color and disabled items use [`State::Disabled`].
+    pub fn style(mut self, state: State, color: Color) -> Self {
+        self.styles.set(state, color);
+        self
+    }
+
+    /// Take the colors for each state from a theme, using the `list` overrides
+    pub fn themed(self, theme: &Theme) -> Self {
+        List {
+            styles: self.styles.themed(theme.states("list")),
+            ..self
+        }
+    }
+
+    /// Every item, from the top level
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+
+    /// Replace the items, keeping the highlight and open submenu where they still exist
+    pub fn set_items<I: Into<Item>>(&mut self, items: impl IntoIterator<Item = I>) {
+        self.items = items.into_iter().map(Into::into).collect();
+        self.pressed = None;
+
+        // Close submenus whose parent item is gone or no longer has children
+        let mut items = &self.items;
+        let mut depth = 0;
+        while let Some(level) = self.path.get(depth) {
+            match items.get(level.selected) {
+                Some(item) if !item.children.is_empty() => items = &item.children,
+                _ => break,
+            }
+            depth += 1;
+        }
+        if depth < self.path.len() {
+            let level = self.path[depth];
+            self.path.truncate(depth);
+            self.selected = level.selected;
+            self.offset = level.offset;
+        }
+
+        let last = self.current().len().saturating_sub(1);
+        self.selected = self.selected.min(last);
+        self.selected = self
+            .next_enabled(self.selected, 1)
+            .or_else(|| self.next_enabled(self.selected, -1))
+            .unwrap_or(self.selected);
+
+        let items = &self.items;
+        self.checked.retain(|path| Self::exists(items, path));
+    }
+
+    /// Whether an item without children is at `path`
+    fn exists(mut items: &[Item], path: &[usize]) -> bool {
+        for index in path {
+            match items.get(*index) {
+                Some(item) => items = &item.children,
+                None => return false,
+            }
+        }
+        items.is_empty()
+    }
+
+    /// Path to the highlighted item
+    pub fn selected(&self) -> Vec<usize> {
+        let mut path = self.parents();
+        path.push(self.selected);
+        path
+    }
+
+    /// Paths of every checked item, in order
+    pub fn checked(&self) -> Vec<Vec<usize>> {
+        self.checked.iter().cloned().collect()
+    }
+
+    /// Path of the open submenu, empty at the top level
+    fn parents(&self) -> Vec<usize> {
+        self.path.iter().map(|level| level.selected).collect()
+    }
+
+    /// The items at the open level
+    pub fn current(&self) -> &[Item] {
+        let mut items = &self.items;
+        for level in self.path.iter() {
+            items = &items[level.selected].children;
+        }
+        items
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+        if disabled {
+            self.focused = false;
+        }
+    }
+
+    /// First enabled item starting at `from` and stepping by `direction`
+    fn next_enabled(&self, from: usize, direction: isize) -> Option<usize> {
+        let items = self.current();
+        let mut index = from as isize;
+        while index >= 0 && (index as usize) < items.len() {
+            if !items[index as usize].disabled {
+                return Some(index as usize);
+            }
+            index += direction;
+        }
+        None
+    }
+
+    /// Move the highlight to the closest enabled item to `to`, searching in `direction` first
+    fn highlight(&mut self, to: usize, direction: isize) -> Option<Message> {
+        let len = self.current().len();
+        if len == 0 {
+            return None;
+        }
+
+        let to = to.min(len - 1);
+        let index = self
+            .next_enabled(to, direction)
+            .or_else(|| self.next_enabled(to, -direction))?;
+        if index == self.selected {
+            return None;
+        }
+
+        self.selected = index;
+        Some(Message::Highlight(self.selected()))
+    }
+
+    /// Open the submenu of the highlighted item, or choose it when it has no children
+    fn activate(&mut self) -> Option<Message> {
+        let item = self.current().get(self.selected)?;
+        if item.disabled {
+            return None;
+        }
+
+        if item.children.is_empty() {
+            return Some(Message::Activate(self.selected()));
+        }
+
+        self.path.push(Level {
+            selected: self.selected,
+            offset: self.offset,
+        });
+        self.offset = 0;
+        self.selected = self.next_enabled(0, 1).unwrap_or(0);
+        Some(Message::Highlight(self.selected()))
+    }
+
+    /// Go back to the parent of the open submenu
+    fn close(&mut self) -> Option<Message> {
+        let level = self.path.pop()?;
+        self.selected = level.selected;
+        self.offset = level.offset;
+        Some(Message::Highlight(self.selected()))
+    }
+
+    fn check(&mut self) -> Option<Message> {
+        let item = self.current().get(self.selected)?;
+        if item.disabled || !item.children.is_empty() {
+            return self.activate();
+        }
+
+        let path = self.selected();
+        let checked = !self.checked.remove(&path);
+        if checked {
+            self.checked.insert(path.clone());
+        }
+        Some(Message::Check(path, checked))
+    }
+
+    /// The area the items are drawn in, below the submenu header
+    fn body(&self, area: &Rect) -> Rect {
+        let header = usize::from(!self.path.is_empty()).min(area.height());
+        Rect::from([area.left, area.top + header, area.right, area.bottom])
+    }
+
+    /// Scroll offset that keeps the highlighted item visible
+    fn visible_offset(&self, height: usize) -> usize {
+        let len = self.current().len();
+        let mut offset = self.offset.min(len.saturating_sub(height));
+        if self.selected < offset {
+            offset = self.selected;
+        } else if height > 0 && self.selected >= offset + height {
+            offset = self.selected + 1 - height;
+        }
+        offset
+    }
+
+    /// Index of the item under a position in the area
+    fn index_at(&self, area: &Rect, x: usize, y: usize) -> Option<usize> {
+        let body = self.body(area);
+        if !body.contains(x, y) {
+            return None;
+        }
+
+        let index = self.offset + y - body.top;
+        (index < self.current().len()).then_some(index)
+    }
+
+    fn color(&self, state: State) -> Color {
+        self.styles.get(&state).cloned().unwrap_or_default()
+    }
+}
+
+impl Widget for List {
+    fn render(&self, buffer: &mut PixelBuffer, area: Rect) {
+        let normal = self.color(State::Normal);
+        let body = self.body(&area);
+        if let Some(parent) = self.path.last() {
+            let mut items = &self.items;
+            for level in self.path[..self.path.len() - 1].iter() {
+                items = &items[level.selected].children;
+            }
+
+            Text::new(Span::new(
+                format!("‹ {}", items[parent.selected].label),
+                self.color(State::Disabled),
+            ))
+            .wrap(Wrap::None)
+            .overflow(Overflow::Ellipsis)
+            .render(
+                buffer,
+                Rect::from([area.left, area.top, area.right, body.top]),
+            );
+        }
+
+        let items = self.current();
+        let height = body.height();
+        let offset = self.visible_offset(height);
+        let scrollbar = height > 0 && items.len() > height && body.width() > 1;
+        let right = body.right - usize::from(scrollbar);
+
+        for (row, (index, item)) in items
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(height)
+            .enumerate()
+        {
+            let color = if self.disabled || item.disabled {
+                self.color(State::Disabled)
+            } else if index == self.selected && self.focused {
+                self.color(State::Focused)
+            } else if index == self.selected {
+                self.color(State::Hovered)
+            } else {
+                normal.clone()
+            };
+
+            let y = body.top + row;
+            let line = Rect::from([body.left, y, right, y + 1]);
+            for x in line.left..line.right {
+                put(buffer, &line, x, y, Pixel::new(' ', color.clone()));
+            }
+
+            let mut path = self.parents();
+            path.push(index);
+            let label = match self.multi && item.children.is_empty() {
+                true if self.checked.contains(&path) => format!("[x] {}", item.label),
+                true => format!("[ ] {}", item.label),
+                false => item.label.clone(),
+            };
+
+            let suffix = usize::from(!item.children.is_empty()).min(line.width());
+            Text::new(Span::new(label, color.clone()))
+                .wrap(Wrap::None)
+                .overflow(Overflow::Ellipsis)
+                .render(
+                    buffer,
+                    Rect::from([line.left, y, line.right - suffix, y + 1]),
+                );
+            if suffix > 0 {
+                put(buffer, &line, line.right - 1, y, Pixel::new('›', color));
+            }
+        }
+
+        if scrollbar {
+            let x = body.right - 1;
+            let thumb = (height * height / items.len()).max(1);
+            let start = offset * (height - thumb) / (items.len() - height);
+            for row in 0..height {
+                let value = match row >= start && row < start + thumb {
+                    true => '█',
+                    false => '│',
+                };
+                put(
+                    buffer,
+                    &body,
+                    x,
+                    body.top + row,
+                    Pixel::new(value, normal.clone()),
+                );
+            }
+        }
+    }
+}
+
+impl Interactive for List {
+    type Message = Message;
+
+    fn handle(&mut self, event: &Event, area: Rect) -> Option<Message> {
+        if self.disabled {
+            return None;
+        }
+
+        let page = self.body(&area).height().max(1);
+        let message = match event {
+            Event::Key(key) if self.focused => match key.key {
+                Key::Up => self.highlight(self.selected.saturating_sub(1), -1),
+                Key::Down => self.highlight(self.selected + 1, 1),
+                Key::PageUp => self.highlight(self.selected.saturating_sub(page), -1),
+                Key::PageDown => self.highlight(self.selected + page, 1),
+                Key::Home => self.highlight(0, 1),
+                Key::End => self.highlight(usize::MAX, -1),
+                Key::Enter | Key::Right => self.activate(),
+                Key::Char(' ') if self.multi => self.check(),
+                Key::Char(' ') => self.activate(),
+                Key::Left | Key::Backspace | Key::Esc => self.close(),
+                _ => None,
+            },
+            Event::Mouse(mouse) => match mouse.kind {
+                MouseKind::Down(MouseButton::Left) => {
+                    self.pressed = self.index_at(&area, mouse.x, mouse.y);
+                    if !self.path.is_empty()
+                        && mouse.y == area.top
+                        && area.contains(mouse.x, mouse.y)
+                    {
+                        return self.close();
+                    }
+                    match self.pressed {
+                        Some(index) if !self.current()[index].disabled => self.highlight(index, 1),
+                        _ => None,
+                    }
+                }
+                MouseKind::Up(MouseButton::Left) => {
+                    let pressed = self.pressed.take();
+                    match self.index_at(&area, mouse.x, mouse.y) {
+                        Some(index) if pressed == Some(index) && index == self.selected => {
+                            match self.multi {
+                                true => self.check(),
+                                false => self.activate(),
+                            }
+                        }
+                        _ => None,
+                    }
+                }
+                MouseKind::ScrollUp if area.contains(mouse.x, mouse.y) => {
+                    self.highlight(self.selected.saturating_sub(1), -1)
+                }
+                MouseKind::ScrollDown if area.contains(mouse.x, mouse.y) => {
+                    self.highlight(self.selected + 1, 1)
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+
+        self.offset = self.visible_offset(self.body(&area).height());
+        message
+    }
+
+    fn focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused && !self.disabled;
+    }
+
+    fn focusable(&self) -> bool {
+        !self.disabled && !self.items.is_empty()
+    }
+}
@@ -0,0 +1,235 @@
+pub mod border;
+pub mod button;
+pub mod focus;
+pub mod gauge;
+pub mod input;
+pub mod list;
+pub mod panel;
+pub mod slider;
+pub mod text;
+pub mod theme;
+pub mod toggle;
+
+pub use border::Border;
+pub use button::Button;
+pub use focus::FocusManager;
+pub use gauge::{Gauge, Spinner};
+pub use input::TextInput;
+pub use list::List;
+pub use panel::{Dialog, Panel};
+pub use slider::Slider;
+pub use text::{Span, Text};
+pub use theme::Theme;
+pub use toggle::{RadioGroup, Toggle};
+
+use std::collections::HashMap;
+
+use crate::core::{
+    buffer::{Buffer, PixelBuffer},
+    color::Color,
+    data::{Pixel, Rect},
+    event::{Event, Key, MouseButton, MouseKind},
+};
+
+/// An element that can be drawn into a region of a [`PixelBuffer`]
+pub trait Widget {
+    /// Draw the element into the `area` of the buffer
+    ///
+    /// Anything that falls outside of the area or the buffer is clipped
+    fn render(&self, buffer: &mut PixelBuffer, area: Rect);
+}
+
+/// An element that reacts to input
+pub trait Interactive {
+    /// What the element reports back when the user does something meaningful with it
+    type Message;
+
+    /// Handle an input event
+    ///
+    /// `area` is where the element was last rendered and is used to hit test mouse
+    /// events. Returns a message when the event changed or activated the element.
+    fn handle(&mut self, event: &Event, area: Rect) -> Option<Self::Message>;
+
+    fn focused(&self) -> bool;
+
+    fn set_focused(&mut self, focused: bool);
+
+    /// Whether the element can currently receive focus
+    fn focusable(&self) -> bool {
+        true
+    }
+}
+
+/// Visual state of an interactive element
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum State {
+    #[default]
+    Normal,
+    Focused,
+    Hovered,
+    Pressed,
+    Disabled,
+}
+
+/// Colors for each [`State`] of an element
+///
+/// Colors set on the element itself take priority over the theme's, so applying a
+/// theme afterwards doesn't undo them.
+#[derive(Debug, Clone)]
+pub(crate) struct Styles {
+    theme: HashMap<State, Color>,
+    custom: HashMap<State, Color>,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Styles {
+            theme: Theme::default().states(""),
+            custom: HashMap::new(),
+        }
+    }
+}
+
+impl Styles {
+    pub fn set(&mut self, state: State, color: Color) {
+        self.custom.insert(state, color);
+    }
+
+    /// Replace the theme's colors, keeping any set on the element
+    pub fn themed(self, theme: HashMap<State, Color>) -> Self {
+        Styles { theme, ..self }
+    }
+
+    pub fn get(&self, state: &State) -> Option<&Color> {
+        self.custom.get(state).or_else(|| self.theme.get(state))
+    }
+}
+
+/// Direction an element is laid out in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+/// Blocks filled from the left in eighths, from 1/8 to 8/8
+const HORIZONTAL_EIGHTHS: [char; 8] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+/// Blocks filled from the bottom in eighths, from 1/8 to 8/8
+const VERTICAL_EIGHTHS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Draw a bar that fills `fraction` of the area with eighth block precision
+///
+/// Horizontal bars fill from the left and vertical bars fill from the bottom.
+/// Cells that are not filled are drawn with `track`.
+pub(crate) fn draw_bar(
+    buffer: &mut PixelBuffer,
+    area: &Rect,
+    fraction: f64,
+    orientation: Orientation,
+    color: &Color,
+    track: &Pixel,
+) {
+    let (length, eighths) = match orientation {
+        Orientation::Horizontal => (area.width(), HORIZONTAL_EIGHTHS),
+        Orientation::Vertical => (area.height(), VERTICAL_EIGHTHS),
+    };
+    let filled = (fraction.clamp(0., 1.) * (length * 8) as f64).round() as usize;
+
+    for i in 0..length {
+        let pixel = match filled.saturating_sub(i * 8).min(8) {
+            0 => track.clone(),
+            n => Pixel::new(eighths[n - 1], color.clone()),
+        };
+
+        match orientation {
+            Orientation::Horizontal => {
+                for y in area.top..area.bottom {
+                    put(buffer, area, area.left + i, y, pixel.clone());
+                }
+            }
+            Orientation::Vertical => {
+                for x in area.left..area.right {
+                    put(buffer, area, x, area.bottom - 1 - i, pixel.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Horizontal alignment of content inside of an area
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Align {
+    #[default]
+    Left,
+    Center,
+    Right,
+    /// Stretch the gaps between words so each line fills the area
+    Justify,
+}
+
+/// Vertical alignment of content inside of an area
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VAlign {
+    #[default]
+    Top,
+    Middle,
+    Bottom,
+}
+
+/// Track a left click that starts and ends inside of the same area
+#[derive(Debug, Default)]
+pub(crate) struct Click {
+    pub pressed: bool,
+    pub hovered: bool,
+}
+
+impl Click {
+    /// Returns true when the event finishes a click inside of the area
+    pub fn update(&mut self, event: &Event, area: &Rect) -> bool {
+        let mouse = match event {
+            Event::Mouse(mouse) => mouse,
+            _ => return false,
+        };
+
+        let inside = area.contains(mouse.x, mouse.y);
+        self.hovered = inside;
+        match mouse.kind {
+            MouseKind::Down(MouseButton::Left) => {
+                self.pressed = inside;
+                false
+            }
+            MouseKind::Up(MouseButton::Left) => {
+                let clicked = self.pressed && inside;
+                self.pressed = false;
+                clicked
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Whether the event is a key that activates the focused element
+pub(crate) fn is_activate(event: &Event) -> bool {
+    matches!(event, Event::Key(key) if matches!(key.key, Key::Enter | Key::Char(' ')))
+}
+
+/// Fill every cell of the area with the pixel
+pub(crate) fn fill(buffer: &mut PixelBuffer, area: &Rect, pixel: Pixel) {
+    for y in area.top..area.bottom {
+        for x in area.left..area.right {
+            put(buffer, area, x, y, pixel.clone());
+        }
+    }
+}
+
+/// Write a pixel into the buffer if it is inside of both the area and the buffer
+pub(crate) fn put(buffer: &mut PixelBuffer, area: &Rect, x: usize, y: usize, pixel: Pixel) {
+    if x < area.left || x >= area.right || y < area.top || y >= area.bottom {
+        return;
+    }
+
+    if let Some(current) = buffer.get_mut(x, y) {
+        *current = pixel;
+    }
+}
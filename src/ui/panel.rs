@@ -0,0 +1,426 @@
+use super::{
+    border::Border,
+    button::Button,
+    fill, is_activate, put,
+    text::{Overflow, Span, Text, Wrap},
+    theme::{Role, Theme},
+    Align, Interactive, Widget,
+};
+use crate::core::{
+    buffer::{Buffer, PixelBuffer},
+    color::Color,
+    data::{Pixel, Rect},
+    event::{Event, Key},
+};
+
+/// A container that draws a border with an optional title and footer
+///
+/// Children are placed relative to the inside of the border and are clipped to it.
+///
+/// # Example
+/// ```
+/// use clige::core::{buffer::{Buffer, PixelBuffer}, data::Rect};
+/// use clige::ui::{Border, Panel, Text, Widget};
+///
+/// let mut buffer = PixelBuffer::new(20, 5);
+/// Panel::new()
+///     .border(Border::Double)
+///     .title("Inventory")
+///     .child(Rect::from([0, 0, 18, 1]), Text::new("Sword"))
+///     .render(&mut buffer, Rect::from([0, 0, 20, 5]));
+/// ```
+#[derive(Default)]
+pub struct Panel {
+    border: Border,
+    title: Option<String>,
+    footer: Option<String>,
+    title_align: Align,
+    color: Option<Color>,
+    /// Border color from the theme, used when no color is set directly
+    theme: Color,
+    background: Option<Color>,
+    children: Vec<(Rect, Box<dyn Widget>)>,
+}
+
+impl Panel {
+    pub fn new() -> Self {
+        Panel {
+            border: Border::Single,
+            ..Default::default()
+        }
+    }
+
+    pub fn border(self, border: Border) -> Self {
+        Panel { border, ..self }
+    }
+
+    /// Text drawn on the top edge
+    pub fn title<S: Into<String>>(self, title: S) -> Self {
+        Panel {
+            title: Some(title.into()),
+            ..self
+        }
+    }
+
+    /// Text drawn on the bottom edge
+    pub fn footer<S: Into<String>>(self, footer: S) -> Self {
+        Panel {
+            footer: Some(footer.into()),
+            ..self
+        }
+    }
+
+    /// Where the title and footer sit along their edge
+    pub fn title_align(self, title_align: Align) -> Self {
+        Panel {
+            title_align,
+            ..self
+        }
+    }
+
+    /// Color of the border, title, and footer
+    pub fn color(self, color: Color) -> Self {
+        Panel {
+            color: Some(color),
+            ..self
+        }
+    }
+
+    /// Take the border color from a theme, using the `panel` overrides
+    ///
+    /// A color set with [`Panel::color`] is kept.
+    pub fn themed(self, theme: &Theme) -> Self {
+        Panel {
+            theme: theme.color("panel", Role::Border),
+            ..self
+        }
+    }
+
+    /// Fill the panel with a color before anything else is drawn
+    pub fn background(self, background: Color) -> Self {
+        Panel {
+            background: Some(background),
+            ..self
+        }
+    }
+
+    /// Add a child placed at `area`, relative to the top left of the panel's inner area
+    pub fn child<W: Widget + 'static>(mut self, area: Rect, widget: W) -> Self {
+        self.children.push((area, Box::new(widget)));
+        self
+    }
+
+    /// The area inside of the border, title, and footer
+    pub fn inner(&self, area: Rect) -> Rect {
+        let mut inner = self.border.inner(area);
+        if self.border == Border::None {
+            if self.title.is_some() && inner.height() > 0 {
+                inner.top += 1;
+            }
+            if self.footer.is_some() && inner.height() > 0 {
+                inner.bottom -= 1;
+            }
+        }
+        inner
+    }
+
+    fn border_color(&self) -> Color {
+        self.color.clone().unwrap_or_else(|| self.theme.clone())
+    }
+
+    fn render_label(&self, buffer: &mut PixelBuffer, area: &Rect, label: &str, y: usize) {
+        // Keep the corners of the border visible
+        let padding = self.border.thickness();
+        let edge = Rect::from([
+            area.left + padding.min(area.width()),
+            y,
+            area.right - padding.min(area.width()),
+            y + 1,
+        ]);
+
+        Text::new(Span::new(format!(" {} ", label), self.border_color()))
+            .wrap(Wrap::None)
+            .align(self.title_align)
+            .overflow(Overflow::Ellipsis)
+            .render(buffer, edge);
+    }
+}
+
+impl Widget for Panel {
+    fn render(&self, buffer: &mut PixelBuffer, area: Rect) {
+        if area.width() == 0 || area.height() == 0 {
+            return;
+        }
+
+        if let Some(background) = self.background.as_ref() {
+            fill(buffer, &area, Pixel::new(' ', background.clone()));
+        }
+        self.border.render(buffer, area, &self.border_color());
+
+        if let Some(title) = self.title.as_ref() {
+            self.render_label(buffer, &area, title, area.top);
+        }
+        if let Some(footer) = self.footer.as_ref() {
+            self.render_label(buffer, &area, footer, area.bottom - 1);
+        }
+
+        let inner = self.inner(area);
+        for (child, widget) in self.children.iter() {
+            render_clipped(
+                buffer,
+                widget.as_ref(),
+                child.offset(inner.left, inner.top),
+                &inner,
+            );
+        }
+    }
+}
+
+/// Render a widget laid out in all of `area`, keeping only the part inside `clip`
+fn render_clipped(buffer: &mut PixelBuffer, widget: &dyn Widget, area: Rect, clip: &Rect) {
+    let visible = area.intersect(clip);
+    if visible.width() == 0 || visible.height() == 0 {
+        return;
+    }
+
+    // Start from what is already drawn, so pixels the widget leaves alone keep it
+    let mut scratch = PixelBuffer::new(area.width(), area.height());
+    for y in visible.top..visible.bottom {
+        for x in visible.left..visible.right {
+            if let Some(pixel) = buffer.get(x, y).cloned() {
+                scratch.pixels[(y - area.top) * area.width() + x - area.left] = pixel;
+            }
+        }
+    }
+
+    widget.render(
+        &mut scratch,
+        Rect::from([0, 0, area.width(), area.height()]),
+    );
+    for y in visible.top..visible.bottom {
+        for x in visible.left..visible.right {
+            let pixel = scratch.pixels[(y - area.top) * area.width() + x - area.left].clone();
+            put(buffer, &visible, x, y, pixel);
+        }
+    }
+}
+
+/// Message sent by a [`Dialog`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Message {
+    /// A button was chosen, holding its index
+    Choose(usize),
+    /// The dialog was closed with `Esc`
+    Dismiss,
+}
+
+/// A modal dialog with a message and a row of buttons
+///
+/// While open the dialog takes every event it is given, so the caller should stop
+/// passing input to anything beneath it. Rendering dims everything already in the
+/// buffer before drawing the dialog centered in the area. `Tab` and the arrow keys
+/// move between buttons, `Enter` or `Space` chooses one, and `Esc` dismisses the
+/// dialog. Choosing or dismissing closes it.
+///
+/// # Example
+/// ```
+/// use clige::core::{data::Rect, event::Key};
+/// use clige::ui::{panel::{Dialog, Message}, Interactive};
+///
+/// let area = Rect::from([0, 0, 40, 12]);
+/// let mut quit = Dialog::new("Quit", "Leave the game?", ["Yes", "No"]);
+/// quit.handle(&Key::Right.into(), area);
+/// assert_eq!(quit.handle(&Key::Enter.into(), area), Some(Message::Choose(1)));
+/// assert!(!quit.is_open());
+/// ```
+pub struct Dialog {
+    panel: Panel,
+    message: Text,
+    buttons: Vec<Button>,
+    selected: usize,
+    size: Option<(usize, usize)>,
+    dim: Option<Color>,
+    open: bool,
+}
+
+impl Dialog {
+    pub fn new<T, M, B>(title: T, message: M, buttons: impl IntoIterator<Item = B>) -> Self
+    where
+        T: Into<String>,
+        M: Into<Span>,
+        B: Into<String>,
+    {
+        let mut dialog = Dialog {
+            panel: Panel::new()
+                .border(Border::Rounded)
+                .title(title)
+                .background(Color::default()),
+            message: Text::new(message).align(Align::Center),
+            buttons: buttons
+                .into_iter()
+                .map(|label| Button::new(format!(" {} ", label.into())).border(Border::None))
+                .collect(),
+            selected: 0,
+            size: None,
+            dim: Some(Color::new("90", "40")),
+            open: true,
+        };
+        dialog.focus(0);
+        dialog
+    }
+
+    /// Replace the frame of the dialog
+    pub fn panel(self, panel: Panel) -> Self {
+        Dialog { panel, ..self }
+    }
+
+    /// Use a fixed width and height instead of fitting the content
+    pub fn size(self, width: usize, height: usize) -> Self {
+        Dialog {
+            size: Some((width, height)),
+            ..self
+        }
+    }
+
+    /// Color that everything beneath the dialog is redrawn with, or `None` to leave it as is
+    pub fn dim(self, dim: Option<Color>) -> Self {
+        Dialog { dim, ..self }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn open(&mut self) {
+        self.open = true;
+        self.focus(0);
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    fn focus(&mut self, index: usize) {
+        self.selected = index;
+        for (i, button) in self.buttons.iter_mut().enumerate() {
+            button.set_focused(i == index);
+        }
+    }
+
+    fn choose(&mut self, index: usize) -> Option<Message> {
+        self.close();
+        Some(Message::Choose(index))
+    }
+
+    /// Where the dialog, its message, and each of its buttons are drawn
+    fn layout(&self, area: &Rect) -> (Rect, Rect, Vec<Rect>) {
+        let widths = self
+            .buttons
+            .iter()
+            .map(|b| Text::new(b.label.as_str()).width())
+            .collect::<Vec<_>>();
+        let row = widths.iter().sum::<usize>() + widths.len().saturating_sub(1);
+        let buttons = usize::from(!self.buttons.is_empty()) * 2;
+
+        let (width, height) = self.size.unwrap_or_else(|| {
+            let frame = self.panel.border.thickness() * 2;
+            let width = (self.message.width().max(row) + frame + 2).min(area.width());
+            let inner = width.saturating_sub(frame + 2);
+            (width, self.message.height(inner) + buttons + frame)
+        });
+        let (width, height) = (width.min(area.width()), height.min(area.height()));
+
+        let left = area.left + (area.width() - width) / 2;
+        let top = area.top + (area.height() - height) / 2;
+        let dialog = Rect::from([left, top, left + width, top + height]);
+
+        let inner = self.panel.inner(dialog);
+        let message = Rect::from([
+            (inner.left + 1).min(inner.right),
+            inner.top,
+            inner.right.saturating_sub(1).max(inner.left),
+            inner.bottom.saturating_sub(buttons).max(inner.top),
+        ]);
+
+        let y = inner.bottom.saturating_sub(1).max(inner.top);
+        let mut x = inner.left + inner.width().saturating_sub(row) / 2;
+        let rects = widths
+            .iter()
+            .map(|width| {
+                let rect = Rect::from([x, y, x + width, y + 1]).intersect(&inner);
+                x += width + 1;
+                rect
+            })
+            .collect();
+
+        (dialog, message, rects)
+    }
+}
+
+impl Widget for Dialog {
+    fn render(&self, buffer: &mut PixelBuffer, area: Rect) {
+        if !self.open {
+            return;
+        }
+
+        if let Some(dim) = self.dim.as_ref() {
+            for y in area.top..area.bottom {
+                for x in area.left..area.right {
+                    if let Some(pixel) = buffer.get_mut(x, y) {
+                        pixel.color = dim.clone();
+                    }
+                }
+            }
+        }
+
+        let (dialog, message, buttons) = self.layout(&area);
+        self.panel.render(buffer, dialog);
+        self.message.render(buffer, message);
+        for (button, rect) in self.buttons.iter().zip(buttons) {
+            button.render(buffer, rect);
+        }
+    }
+}
+
+impl Interactive for Dialog {
+    type Message = Message;
+
+    fn handle(&mut self, event: &Event, area: Rect) -> Option<Message> {
+        if !self.open {
+            return None;
+        }
+
+        let count = self.buttons.len();
+        if let Event::Key(key) = event {
+            match key.key {
+                Key::Esc => {
+                    self.close();
+                    return Some(Message::Dismiss);
+                }
+                Key::Tab | Key::Right if count > 0 => self.focus((self.selected + 1) % count),
+                Key::BackTab | Key::Left if count > 0 => {
+                    self.focus((self.selected + count - 1) % count)
+                }
+                _ if is_activate(event) && count > 0 => return self.choose(self.selected),
+                _ => {}
+            }
+            return None;
+        }
+
+        let (_, _, rects) = self.layout(&area);
+        let mut chosen = None;
+        for (index, (button, rect)) in self.buttons.iter_mut().zip(rects).enumerate() {
+            if button.handle(event, rect).is_some() {
+                chosen = Some(index);
+            }
+        }
+        chosen.and_then(|index| self.choose(index))
+    }
+
+    fn focused(&self) -> bool {
+        self.open
+    }
+
+    /// An open dialog always has focus
+    fn set_focused(&mut self, _: bool) {}
+}
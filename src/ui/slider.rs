@@ -0,0 +1,404 @@
+use super::{
+    draw_bar,
+    text::{Span, Text, Wrap},
+    theme::Theme,
+    Align, Interactive, Orientation, State, Styles, Widget,
+};
+use crate::core::{
+    buffer::PixelBuffer,
+    color::Color,
+    data::{Pixel, Rect},
+    event::{Event, Key, MouseButton, MouseKind},
+};
+
+/// A number that can be adjusted with a [`Slider`]
+pub trait Value: Copy + PartialOrd {
+    /// Smallest step the value can move by, such as `1.0` for integers
+    const MIN_STEP: f64;
+
+    fn to_f64(self) -> f64;
+
+    /// Convert back from a float, rounding for integer types
+    fn from_f64(value: f64) -> Self;
+
+    /// Format the value for the slider's label
+    fn label(self, precision: usize) -> String;
+}
+
+macro_rules! integer_value {
+    ($($ty: ty),*) => {$(
+        impl Value for $ty {
+            const MIN_STEP: f64 = 1.;
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value.round() as $ty
+            }
+
+            fn label(self, _: usize) -> String {
+                self.to_string()
+            }
+        }
+    )*};
+}
+
+macro_rules! float_value {
+    ($($ty: ty),*) => {$(
+        impl Value for $ty {
+            const MIN_STEP: f64 = 0.;
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value as $ty
+            }
+
+            fn label(self, precision: usize) -> String {
+                format!("{:.*}", precision, self)
+            }
+        }
+    )*};
+}
+
+integer_value!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+float_value!(f32, f64);
+
+/// Message sent when the value of a [`Slider`] changes, holding the new value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Changed<T>(pub T);
+
+/// Number of steps moved by `PageUp` and `PageDown`
+const PAGE: f64 = 10.;
+
+/// Pick a number in a range by dragging a bar
+///
+/// While focused the arrow keys move by one step, `PageUp` and `PageDown` move by
+/// ten steps, and `Home` and `End` jump to the ends of the range. Pressing the
+/// bar with the mouse and dragging sets the value from the mouse position.
+///
+/// # Example
+/// ```
+/// use clige::ui::Slider;
+///
+/// let mut frequency = Slider::new(0.0, 2.0).step(0.05).value(1.0).label(true);
+/// frequency.set_value(1.26);
+/// assert_eq!(frequency.get(), 1.25);
+/// ```
+pub struct Slider<T: Value = f64> {
+    min: T,
+    max: T,
+    step: Option<T>,
+    value: T,
+    orientation: Orientation,
+    label: bool,
+    precision: usize,
+    styles: Styles,
+    track: Option<Pixel>,
+    on_change: Option<Box<dyn FnMut(T)>>,
+    dragging: bool,
+    disabled: bool,
+    focused: bool,
+}
+
+impl<T: Value> Slider<T> {
+    /// Create a slider over the inclusive range starting at `min`
+    pub fn new(min: T, max: T) -> Self {
+        Slider {
+            min,
+            max,
+            step: None,
+            value: min,
+            orientation: Orientation::Horizontal,
+            label: false,
+            precision: 2,
+            styles: Styles::default(),
+            track: None,
+            on_change: None,
+            dragging: false,
+            disabled: false,
+            focused: false,
+        }
+    }
+
+    /// Snap values to multiples of `step` from the start of the range
+    ///
+    /// Without a step, keyboard movement uses a hundredth of the range, and at least
+    /// one for integer sliders.
+    pub fn step(self, step: T) -> Self {
+        Slider {
+            step: Some(step),
+            ..self
+        }
+    }
+
+    /// Set the starting value
+    pub fn value(mut self, value: T) -> Self {
+        self.value = self.snap(value.to_f64());
+        self
+    }
+
+    pub fn orientation(self, orientation: Orientation) -> Self {
+        Slider {
+            orientation,
+            ..self
+        }
+    }
+
+    /// Show the current value next to the bar
+    pub fn label(self, label: bool) -> Self {
+        Slider { label, ..self }
+    }
+
+    /// Number of decimal places shown in the label for floating point values
+    pub fn precision(self, precision: usize) -> Self {
+        Slider { precision, ..self }
+    }
+
+    /// Set the color of the filled part of the bar while the slider is in the given state
+    pub fn style(mut self, state: State, color: Color) -> Self {
+        self.styles.set(state, color);
+        self
+    }
+
+    /// Take the colors for each state from a theme, using the `slider` overrides
+    pub fn themed(self, theme: &Theme) -> Self {
+        Slider {
+            styles: self.styles.themed(theme.states("slider")),
+            ..self
+        }
+    }
+
+    /// Set the pixel drawn in the unfilled part of the bar
+    ///
+    /// Defaults to a line running in the direction of the slider.
+    pub fn track(self, track: Pixel) -> Self {
+        Slider {
+            track: Some(track),
+            ..self
+        }
+    }
+
+    /// Set the callback that is run with the new value each time the slider changes
+    pub fn on_change<F: FnMut(T) + 'static>(self, callback: F) -> Self {
+        Slider {
+            on_change: Some(Box::new(callback)),
+            ..self
+        }
+    }
+
+    pub fn get(&self) -> T {
+        self.value
+    }
+
+    /// Set the value, snapping it to the range and step, without emitting a change
+    pub fn set_value(&mut self, value: T) {
+        self.value = self.snap(value.to_f64());
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+        if disabled {
+            self.focused = false;
+            self.dragging = false;
+        }
+    }
+
+    /// How far along the range the value is, from `0.0` to `1.0`
+    pub fn fraction(&self) -> f64 {
+        let range = self.max.to_f64() - self.min.to_f64();
+        if range == 0. {
+            return 0.;
+        }
+        (self.value.to_f64() - self.min.to_f64()) / range
+    }
+
+    pub fn state(&self) -> State {
+        if self.disabled {
+            State::Disabled
+        } else if self.dragging {
+            State::Pressed
+        } else if self.focused {
+            State::Focused
+        } else {
+            State::Normal
+        }
+    }
+
+    fn step_size(&self) -> f64 {
+        match self.step {
+            Some(step) => step.to_f64(),
+            None => {
+                let range = self.max.to_f64() - self.min.to_f64();
+                (range / 100.).abs().max(T::MIN_STEP).copysign(range)
+            }
+        }
+    }
+
+    fn snap(&self, value: f64) -> T {
+        let (min, max) = (self.min.to_f64(), self.max.to_f64());
+        let mut value = value.clamp(min.min(max), max.max(min));
+
+        if let Some(step) = self.step.map(Value::to_f64).filter(|s| *s > 0.) {
+            value = min + ((value - min) / step).round() * step;
+            // Snapping can't leave the range when it doesn't divide evenly by the step
+            if value > max.max(min) {
+                value -= step;
+            }
+        }
+        T::from_f64(value)
+    }
+
+    /// Move the value to a new position and emit a change if it moved
+    fn update(&mut self, value: f64) -> Option<Changed<T>> {
+        let value = self.snap(value);
+        if value == self.value {
+            return None;
+        }
+
+        self.value = value;
+        if let Some(callback) = self.on_change.as_mut() {
+            callback(value);
+        }
+        Some(Changed(value))
+    }
+
+    /// Split the area into the bar and the label
+    fn layout(&self, area: Rect) -> (Rect, Rect) {
+        if !self.label {
+            return (
+                area,
+                Rect::from([area.left, area.bottom, area.right, area.bottom]),
+            );
+        }
+
+        match self.orientation {
+            Orientation::Horizontal => {
+                let size = self
+                    .min
+                    .label(self.precision)
+                    .len()
+                    .max(self.max.label(self.precision).len())
+                    + 1;
+                let split = area.right.saturating_sub(size).max(area.left);
+                (
+                    Rect::from([area.left, area.top, split, area.bottom]),
+                    Rect::from([split, area.top, area.right, area.bottom]),
+                )
+            }
+            Orientation::Vertical => {
+                let split = area.bottom.saturating_sub(1).max(area.top);
+                (
+                    Rect::from([area.left, area.top, area.right, split]),
+                    Rect::from([area.left, split, area.right, area.bottom]),
+                )
+            }
+        }
+    }
+
+    /// The value under a position in the bar
+    fn value_at(&self, bar: &Rect, x: usize, y: usize) -> f64 {
+        let fraction = match self.orientation {
+            Orientation::Horizontal if bar.width() > 1 => {
+                (x.clamp(bar.left, bar.right - 1) - bar.left) as f64 / (bar.width() - 1) as f64
+            }
+            Orientation::Vertical if bar.height() > 1 => {
+                (bar.bottom - 1 - y.clamp(bar.top, bar.bottom - 1)) as f64
+                    / (bar.height() - 1) as f64
+            }
+            _ => self.fraction(),
+        };
+
+        let (min, max) = (self.min.to_f64(), self.max.to_f64());
+        min + (max - min) * fraction
+    }
+}
+
+impl<T: Value> Widget for Slider<T> {
+    fn render(&self, buffer: &mut PixelBuffer, area: Rect) {
+        let color = self.styles.get(&self.state()).cloned().unwrap_or_default();
+        let (bar, label) = self.layout(area);
+        let track = self
+            .track
+            .clone()
+            .unwrap_or_else(|| match self.orientation {
+                Orientation::Horizontal => Pixel::new('─', Color::default()),
+                Orientation::Vertical => Pixel::new('│', Color::default()),
+            });
+
+        draw_bar(
+            buffer,
+            &bar,
+            self.fraction(),
+            self.orientation,
+            &color,
+            &track,
+        );
+
+        if self.label {
+            Text::new(Span::new(self.value.label(self.precision), color))
+                .wrap(Wrap::None)
+                .align(Align::Right)
+                .render(buffer, label);
+        }
+    }
+}
+
+impl<T: Value> Interactive for Slider<T> {
+    type Message = Changed<T>;
+
+    fn handle(&mut self, event: &Event, area: Rect) -> Option<Changed<T>> {
+        if self.disabled {
+            return None;
+        }
+
+        let current = self.value.to_f64();
+        let step = self.step_size();
+
+        match event {
+            Event::Key(key) if self.focused => match key.key {
+                Key::Right | Key::Up => self.update(current + step),
+                Key::Left | Key::Down => self.update(current - step),
+                Key::PageUp => self.update(current + step * PAGE),
+                Key::PageDown => self.update(current - step * PAGE),
+                Key::Home => self.update(self.min.to_f64()),
+                Key::End => self.update(self.max.to_f64()),
+                _ => None,
+            },
+            Event::Mouse(mouse) => {
+                let (bar, _) = self.layout(area);
+                match mouse.kind {
+                    MouseKind::Down(MouseButton::Left) if bar.contains(mouse.x, mouse.y) => {
+                        self.dragging = true;
+                        self.update(self.value_at(&bar, mouse.x, mouse.y))
+                    }
+                    MouseKind::Drag(MouseButton::Left) if self.dragging => {
+                        self.update(self.value_at(&bar, mouse.x, mouse.y))
+                    }
+                    MouseKind::Up(MouseButton::Left) => {
+                        self.dragging = false;
+                        None
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused && !self.disabled;
+    }
+
+    fn focusable(&self) -> bool {
+        !self.disabled
+    }
+}
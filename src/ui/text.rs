@@ -0,0 +1,440 @@
+use super::{put, Align, VAlign, Widget};
+use crate::core::{
+    buffer::PixelBuffer,
+    color::Color,
+    data::{char_width, Pixel, Rect},
+};
+
+/// Character used when content is truncated with [`Overflow::Ellipsis`]
+const ELLIPSIS: char = '…';
+
+/// A run of characters that share the same color
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    pub content: String,
+    pub color: Color,
+}
+
+impl Span {
+    pub fn new<S: Into<String>>(content: S, color: Color) -> Self {
+        Span {
+            content: content.into(),
+            color,
+        }
+    }
+}
+
+impl From<&str> for Span {
+    fn from(value: &str) -> Self {
+        Span::new(value, Color::default())
+    }
+}
+
+impl From<String> for Span {
+    fn from(value: String) -> Self {
+        Span::new(value, Color::default())
+    }
+}
+
+/// How lines that are wider than the area are broken up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Wrap {
+    /// Never break lines; anything past the right edge overflows
+    None,
+    /// Break at the last character that fits
+    Char,
+    /// Break between words, only splitting words that are wider than the area
+    #[default]
+    Word,
+}
+
+/// What happens to content that does not fit inside of the area
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow {
+    #[default]
+    Clip,
+    /// Replace the last visible character with `…`
+    Ellipsis,
+}
+
+#[derive(Debug, Clone)]
+struct Cell {
+    value: char,
+    color: Color,
+    width: usize,
+}
+
+impl Cell {
+    fn is_space(&self) -> bool {
+        self.value.is_whitespace()
+    }
+}
+
+#[derive(Debug, Default)]
+struct Line {
+    cells: Vec<Cell>,
+    /// Last line of a paragraph. These lines are never justified
+    end: bool,
+}
+
+impl Line {
+    fn width(&self) -> usize {
+        self.cells.iter().map(|c| c.width).sum()
+    }
+
+    fn trim_end(&mut self) {
+        while self.cells.last().is_some_and(Cell::is_space) {
+            self.cells.pop();
+        }
+    }
+
+    /// Cut the line down to `width` columns, optionally ending it with an ellipsis
+    fn truncate(&mut self, width: usize, ellipsis: bool) {
+        let limit = if ellipsis {
+            width.saturating_sub(1)
+        } else {
+            width
+        };
+        let color = self.cells.last().map(|c| c.color.clone());
+
+        while self.width() > limit {
+            self.cells.pop();
+        }
+
+        if ellipsis && width > 0 {
+            self.cells.push(Cell {
+                value: ELLIPSIS,
+                color: color.unwrap_or_default(),
+                width: 1,
+            });
+        }
+    }
+
+    /// Widen the gaps between words until the line is `width` columns wide
+    fn justify(&mut self, width: usize) {
+        let current = self.width();
+        // Each gap is the index of the first space in a run of spaces between words
+        let gaps = self
+            .cells
+            .iter()
+            .enumerate()
+            .filter(|(i, c)| c.is_space() && *i > 0 && !self.cells[i - 1].is_space())
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        if self.end || gaps.is_empty() || current >= width {
+            return;
+        }
+
+        let extra = width - current;
+        for (n, index) in gaps.iter().enumerate().rev() {
+            let count = extra / gaps.len() + usize::from(n < extra % gaps.len());
+            let fill = Cell {
+                value: ' ',
+                ..self.cells[*index].clone()
+            };
+            self.cells
+                .splice(index..index, std::iter::repeat_n(fill, count));
+        }
+    }
+}
+
+/// Styled text that is laid out inside of a [`Rect`]
+///
+/// # Example
+/// ```
+/// use clige::core::{buffer::{Buffer, PixelBuffer}, data::Rect};
+/// use clige::ui::{text::{Overflow, Text, Wrap}, Align, Widget};
+///
+/// let mut buffer = PixelBuffer::new(11, 2);
+/// Text::new("The quick brown fox jumps")
+///     .wrap(Wrap::Word)
+///     .align(Align::Center)
+///     .overflow(Overflow::Ellipsis)
+///     .render(&mut buffer, Rect::from([0, 0, 11, 2]));
+///
+/// let rows: Vec<String> = buffer
+///     .pixels
+///     .chunks(11)
+///     .map(|row| row.iter().map(|p| p.value).collect())
+///     .collect();
+/// assert_eq!(rows, [" The quick ", "brown fox… "]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Text {
+    pub spans: Vec<Span>,
+    wrap: Wrap,
+    align: Align,
+    valign: VAlign,
+    overflow: Overflow,
+}
+
+impl Text {
+    pub fn new<S: Into<Span>>(span: S) -> Self {
+        Text {
+            spans: vec![span.into()],
+            ..Default::default()
+        }
+    }
+
+    pub fn styled(spans: Vec<Span>) -> Self {
+        Text {
+            spans,
+            ..Default::default()
+        }
+    }
+
+    /// Append a span to the end of the text
+    pub fn push<S: Into<Span>>(&mut self, span: S) {
+        self.spans.push(span.into());
+    }
+
+    pub fn wrap(self, wrap: Wrap) -> Self {
+        Text { wrap, ..self }
+    }
+
+    pub fn align(self, align: Align) -> Self {
+        Text { align, ..self }
+    }
+
+    pub fn valign(self, valign: VAlign) -> Self {
+        Text { valign, ..self }
+    }
+
+    pub fn overflow(self, overflow: Overflow) -> Self {
+        Text { overflow, ..self }
+    }
+
+    /// The text without any styling
+    pub fn content(&self) -> String {
+        self.spans.iter().map(|s| s.content.as_str()).collect()
+    }
+
+    /// Width of the widest line before any wrapping is applied
+    pub fn width(&self) -> usize {
+        self.paragraphs()
+            .iter()
+            .map(|p| p.iter().map(|c| c.width).sum())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Number of rows the text needs when laid out `width` columns wide
+    pub fn height(&self, width: usize) -> usize {
+        self.layout(width).len()
+    }
+
+    /// The rows of text as they would be rendered `width` columns wide, without styling
+    pub fn lines(&self, width: usize) -> Vec<String> {
+        self.layout(width)
+            .into_iter()
+            .map(|l| l.cells.iter().map(|c| c.value).collect())
+            .collect()
+    }
+
+    /// Split the spans into hard lines of styled cells.
+    ///
+    /// Characters without a display width, such as combining marks and control
+    /// characters, can't be stored in a pixel and are dropped.
+    fn paragraphs(&self) -> Vec<Vec<Cell>> {
+        let mut paragraphs = vec![Vec::new()];
+
+        for span in self.spans.iter() {
+            for value in span.content.chars() {
+                if value == '\n' {
+                    paragraphs.push(Vec::new());
+                    continue;
+                }
+
+                let (value, width) = match value {
+                    '\t' => (' ', 1),
+                    _ => (value, char_width(value)),
+                };
+
+                if width > 0 {
+                    paragraphs.last_mut().unwrap().push(Cell {
+                        value,
+                        color: span.color.clone(),
+                        width,
+                    });
+                }
+            }
+        }
+
+        paragraphs
+    }
+
+    fn layout(&self, width: usize) -> Vec<Line> {
+        let mut lines = Vec::new();
+
+        for paragraph in self.paragraphs() {
+            match self.wrap {
+                Wrap::None => lines.push(Line {
+                    cells: paragraph,
+                    end: true,
+                }),
+                Wrap::Char => wrap_chars(paragraph, width, &mut lines),
+                Wrap::Word => wrap_words(paragraph, width, &mut lines),
+            }
+
+            if let Some(last) = lines.last_mut() {
+                last.end = true;
+            }
+        }
+
+        if self.align == Align::Justify {
+            for line in lines.iter_mut() {
+                line.justify(width);
+            }
+        }
+
+        lines
+    }
+}
+
+/// Break a paragraph at the last character that fits on each line
+fn wrap_chars(paragraph: Vec<Cell>, width: usize, lines: &mut Vec<Line>) {
+    let mut line = Line::default();
+    let mut used = 0;
+
+    for cell in paragraph {
+        if used + cell.width > width && !line.cells.is_empty() {
+            lines.push(std::mem::take(&mut line));
+            used = 0;
+        }
+        used += cell.width;
+        line.cells.push(cell);
+    }
+
+    lines.push(line);
+}
+
+/// Break a paragraph between words.
+///
+/// Whitespace at a soft break is dropped, and words wider than the whole line
+/// are split by character.
+fn wrap_words(paragraph: Vec<Cell>, width: usize, lines: &mut Vec<Line>) {
+    let mut words: Vec<Vec<Cell>> = Vec::new();
+    for cell in paragraph {
+        match words.last_mut() {
+            Some(word) if word[0].is_space() == cell.is_space() => word.push(cell),
+            _ => words.push(vec![cell]),
+        }
+    }
+
+    let mut line = Line::default();
+    let mut used = 0;
+    let mut wrapped = false;
+
+    for word in words {
+        let size: usize = word.iter().map(|c| c.width).sum();
+
+        if word[0].is_space() {
+            if used + size > width {
+                line.trim_end();
+                lines.push(std::mem::take(&mut line));
+                used = 0;
+                wrapped = true;
+            } else if !(wrapped && line.cells.is_empty()) {
+                used += size;
+                line.cells.extend(word);
+            }
+            continue;
+        }
+
+        if used + size > width && !line.cells.is_empty() {
+            line.trim_end();
+            // Indentation alone is not worth a line of its own
+            if !line.cells.is_empty() {
+                lines.push(std::mem::take(&mut line));
+                wrapped = true;
+            }
+            used = 0;
+        }
+
+        if size > width {
+            for cell in word {
+                if used + cell.width > width && !line.cells.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                    used = 0;
+                    wrapped = true;
+                }
+                used += cell.width;
+                line.cells.push(cell);
+            }
+        } else {
+            used += size;
+            line.cells.extend(word);
+        }
+    }
+
+    line.trim_end();
+    lines.push(line);
+}
+
+impl Widget for Text {
+    fn render(&self, buffer: &mut PixelBuffer, area: Rect) {
+        let (width, height) = (area.width(), area.height());
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let ellipsis = self.overflow == Overflow::Ellipsis;
+        let mut lines = self.layout(width);
+
+        if lines.len() > height {
+            lines.truncate(height);
+            if ellipsis {
+                let last = lines.last_mut().unwrap();
+                last.trim_end();
+                if last.width() >= width {
+                    last.truncate(width, true);
+                } else {
+                    last.truncate(last.width() + 1, true);
+                }
+            }
+        }
+
+        let top = area.top
+            + match self.valign {
+                VAlign::Top => 0,
+                VAlign::Middle => (height - lines.len()) / 2,
+                VAlign::Bottom => height - lines.len(),
+            };
+
+        for (row, mut line) in lines.into_iter().enumerate() {
+            if line.width() > width {
+                line.truncate(width, ellipsis);
+            }
+
+            let free = width - line.width();
+            let mut x = area.left
+                + match self.align {
+                    Align::Left | Align::Justify => 0,
+                    Align::Center => free / 2,
+                    Align::Right => free,
+                };
+
+            let y = top + row;
+            for cell in line.cells {
+                put(
+                    buffer,
+                    &area,
+                    x,
+                    y,
+                    Pixel::new(cell.value, cell.color.clone()),
+                );
+                for offset in 1..cell.width {
+                    put(
+                        buffer,
+                        &area,
+                        x + offset,
+                        y,
+                        Pixel::new(Pixel::CONTINUATION, cell.color.clone()),
+                    );
+                }
+                x += cell.width;
+            }
+        }
+    }
+}
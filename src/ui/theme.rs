@@ -0,0 +1,309 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use super::State;
+use crate::core::color::{Color, Style, SystemColor, Tone};
+
+/// What a style is used for, so widgets can ask a [`Theme`] for colors by meaning
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    Text,
+    Primary,
+    Accent,
+    Warning,
+    Error,
+    Success,
+    Border,
+    Focused,
+    Hovered,
+    Pressed,
+    Disabled,
+    Selection,
+    /// Less important text such as placeholders and hints
+    Muted,
+}
+
+impl Role {
+    pub const ALL: [Role; 13] = [
+        Role::Text,
+        Role::Primary,
+        Role::Accent,
+        Role::Warning,
+        Role::Error,
+        Role::Success,
+        Role::Border,
+        Role::Focused,
+        Role::Hovered,
+        Role::Pressed,
+        Role::Disabled,
+        Role::Selection,
+        Role::Muted,
+    ];
+
+    /// Name of the role as written in theme files
+    pub fn name(&self) -> &'static str {
+        match self {
+            Role::Text => "text",
+            Role::Primary => "primary",
+            Role::Accent => "accent",
+            Role::Warning => "warning",
+            Role::Error => "error",
+            Role::Success => "success",
+            Role::Border => "border",
+            Role::Focused => "focused",
+            Role::Hovered => "hovered",
+            Role::Pressed => "pressed",
+            Role::Disabled => "disabled",
+            Role::Selection => "selection",
+            Role::Muted => "muted",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Role> {
+        Role::ALL.into_iter().find(|role| role.name() == name)
+    }
+}
+
+/// A set of styles for each [`Role`], with optional overrides for a single kind of widget
+///
+/// Widgets take a theme through their `themed` builder, which looks up the roles
+/// they use under their own name first, e.g. `button.focused`, and falls back to the
+/// shared role.
+///
+/// # Example
+/// ```
+/// use clige::ui::{theme::{Role, Theme}, Button};
+///
+/// let theme = Theme::parse(
+///     "base = light
+///     primary = #005fd7 bold
+///     button.focused = white bg:magenta",
+/// )
+/// .unwrap();
+/// assert!(theme.style(Role::Primary).bold);
+///
+/// let button = Button::new("Play").themed(&theme);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    roles: HashMap<Role, Style>,
+    overrides: HashMap<(String, Role), Style>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::dark()
+    }
+}
+
+impl Theme {
+    fn from_roles(roles: impl IntoIterator<Item = (Role, Style)>) -> Self {
+        Theme {
+            roles: roles.into_iter().collect(),
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Light text on a dark terminal
+    pub fn dark() -> Self {
+        use SystemColor::*;
+        Theme::from_roles([
+            (Role::Text, Style::new()),
+            (Role::Primary, Style::new().fg(Tone::Bright(Blue))),
+            (Role::Accent, Style::new().fg(Tone::Bright(Magenta))),
+            (Role::Warning, Style::new().fg(Tone::Bright(Yellow))),
+            (Role::Error, Style::new().fg(Tone::Bright(Red))),
+            (Role::Success, Style::new().fg(Tone::Bright(Green))),
+            (Role::Border, Style::new()),
+            (Role::Focused, Style::new().fg(Tone::Bright(White)).bg(Blue)),
+            (
+                Role::Hovered,
+                Style::new().fg(Tone::Bright(White)).bg(Tone::Bright(Black)),
+            ),
+            (
+                Role::Pressed,
+                Style::new().fg(Black).bg(Tone::Bright(White)),
+            ),
+            (Role::Disabled, Style::new().fg(Tone::Bright(Black))),
+            (Role::Selection, Style::new().fg(Black).bg(Cyan)),
+            (Role::Muted, Style::new().fg(Tone::Bright(Black))),
+        ])
+    }
+
+    /// Dark text on a light terminal
+    pub fn light() -> Self {
+        use SystemColor::*;
+        Theme::from_roles([
+            (Role::Text, Style::new().fg(Black)),
+            (Role::Primary, Style::new().fg(Blue)),
+            (Role::Accent, Style::new().fg(Magenta)),
+            (Role::Warning, Style::new().fg(Yellow)),
+            (Role::Error, Style::new().fg(Red)),
+            (Role::Success, Style::new().fg(Green)),
+            (Role::Border, Style::new().fg(Black)),
+            (Role::Focused, Style::new().fg(Tone::Bright(White)).bg(Blue)),
+            (Role::Hovered, Style::new().fg(Black).bg(White)),
+            (
+                Role::Pressed,
+                Style::new().fg(Tone::Bright(White)).bg(Black),
+            ),
+            (Role::Disabled, Style::new().fg(Tone::Bright(Black))),
+            (
+                Role::Selection,
+                Style::new().fg(Black).bg(Tone::Bright(Cyan)),
+            ),
+            (Role::Muted, Style::new().fg(Tone::Bright(Black))),
+        ])
+    }
+
+    /// Only text attributes, for terminals without color
+    pub fn mono() -> Self {
+        Theme::from_roles([
+            (Role::Text, Style::new()),
+            (Role::Primary, Style::new().bold(true)),
+            (Role::Accent, Style::new().italic(true)),
+            (Role::Warning, Style::new().bold(true)),
+            (Role::Error, Style::new().bold(true).underline(true)),
+            (Role::Success, Style::new().bold(true)),
+            (Role::Border, Style::new()),
+            (Role::Focused, Style::new().reverse(true)),
+            (Role::Hovered, Style::new().underline(true)),
+            (Role::Pressed, Style::new().reverse(true).bold(true)),
+            (Role::Disabled, Style::new().dim(true)),
+            (Role::Selection, Style::new().reverse(true)),
+            (Role::Muted, Style::new().dim(true)),
+        ])
+    }
+
+    /// Look up a built in theme by name: `dark`, `light`, or `mono`
+    pub fn builtin(name: &str) -> Option<Theme> {
+        match name {
+            "dark" => Some(Theme::dark()),
+            "light" => Some(Theme::light()),
+            "mono" => Some(Theme::mono()),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, role: Role, style: Style) {
+        self.roles.insert(role, style);
+    }
+
+    /// Use a different style for a role only in one kind of widget, e.g. `"button"`
+    pub fn set_override<S: Into<String>>(&mut self, widget: S, role: Role, style: Style) {
+        self.overrides.insert((widget.into(), role), style);
+    }
+
+    pub fn style(&self, role: Role) -> Style {
+        self.roles.get(&role).copied().unwrap_or_default()
+    }
+
+    /// Style of a role for a kind of widget, falling back to the shared role
+    pub fn style_for(&self, widget: &str, role: Role) -> Style {
+        self.overrides
+            .get(&(widget.to_string(), role))
+            .copied()
+            .unwrap_or_else(|| self.style(role))
+    }
+
+    pub fn color(&self, widget: &str, role: Role) -> Color {
+        self.style_for(widget, role).color()
+    }
+
+    /// Colors for each [`State`] of an interactive widget
+    pub fn states(&self, widget: &str) -> HashMap<State, Color> {
+        HashMap::from([
+            (State::Normal, self.color(widget, Role::Text)),
+            (State::Focused, self.color(widget, Role::Focused)),
+            (State::Hovered, self.color(widget, Role::Hovered)),
+            (State::Pressed, self.color(widget, Role::Pressed)),
+            (State::Disabled, self.color(widget, Role::Disabled)),
+        ])
+    }
+
+    /// Parse a theme from the text of a theme file
+    ///
+    /// Each line is `role = style` or `widget.role = style`, and lines starting with
+    /// `#` are comments. A `base = dark|light|mono` line picks the theme the rest of
+    /// the file changes, which is `dark` when not given. The base should come first
+    /// as it replaces every role set before it.
+    ///
+    /// A style is a list of words separated by spaces. A color on its own sets the
+    /// foreground, `fg:<color>` and `bg:<color>` set either side, and `bold`, `dim`,
+    /// `italic`, `underline`, and `reverse` turn on attributes. Colors are names like
+    /// `red` or `bright-red`, xterm codes from `0` to `255`, hex like `#ff8700`, or
+    /// `default` for the terminal's own color.
+    pub fn parse(source: &str) -> Result<Theme, String> {
+        let mut theme = Theme::dark();
+
+        for (number, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = |message: String| format!("line {}: {}", number + 1, message);
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error(format!("expected `key = style` but found '{}'", line)))?;
+            let (key, value) = (key.trim(), value.trim());
+
+            if key == "base" {
+                let base = Theme::builtin(value)
+                    .ok_or_else(|| error(format!("unknown base theme '{}'", value)))?;
+                theme.roles = base.roles;
+                continue;
+            }
+
+            let style = parse_style(value).map_err(error)?;
+            let (widget, role) = match key.split_once('.') {
+                Some((widget, role)) => (Some(widget.trim()), role.trim()),
+                None => (None, key),
+            };
+            let role =
+                Role::from_name(role).ok_or_else(|| error(format!("unknown role '{}'", role)))?;
+
+            match widget {
+                Some(widget) => theme.set_override(widget, role, style),
+                None => theme.set(role, style),
+            }
+        }
+
+        Ok(theme)
+    }
+
+    /// Read and parse a theme file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Theme, String> {
+        let source = fs::read_to_string(path.as_ref())
+            .map_err(|e| format!("failed to read {}: {}", path.as_ref().display(), e))?;
+        Theme::parse(&source)
+    }
+}
+
+fn parse_tone(value: &str) -> Result<Option<Tone>, String> {
+    match value {
+        "default" => Ok(None),
+        _ => Tone::parse(value)
+            .map(Some)
+            .ok_or_else(|| format!("unknown color '{}'", value)),
+    }
+}
+
+fn parse_style(value: &str) -> Result<Style, String> {
+    let mut style = Style::new();
+    for word in value.split_whitespace() {
+        match word {
+            "bold" => style.bold = true,
+            "dim" => style.dim = true,
+            "italic" => style.italic = true,
+            "underline" => style.underline = true,
+            "reverse" => style.reverse = true,
+            _ => match word.split_once(':') {
+                Some(("fg", color)) => style.fg = parse_tone(color)?,
+                Some(("bg", color)) => style.bg = parse_tone(color)?,
+                Some(_) => return Err(format!("unknown style '{}'", word)),
+                None => style.fg = parse_tone(word)?,
+            },
+        }
+    }
+    Ok(style)
+}
@@ -0,0 +1,386 @@
+use super::{
+    is_activate,
+    text::{Overflow, Span, Text, Wrap},
+    theme::Theme,
+    Click, Interactive, State, Styles, Widget,
+};
+use crate::core::{
+    buffer::PixelBuffer,
+    color::Color,
+    data::Rect,
+    event::{Event, Key, MouseButton, MouseKind},
+};
+
+/// Which characters are used to draw toggles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Glyphs {
+    /// Plain ASCII for terminals and fonts without box drawing support
+    Ascii,
+    #[default]
+    Unicode,
+}
+
+/// The look of a [`Toggle`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Kind {
+    #[default]
+    Checkbox,
+    Switch,
+}
+
+impl Kind {
+    fn glyph(&self, glyphs: Glyphs, on: bool) -> &'static str {
+        match (self, glyphs, on) {
+            (Kind::Checkbox, Glyphs::Ascii, true) => "[x]",
+            (Kind::Checkbox, Glyphs::Ascii, false) => "[ ]",
+            (Kind::Checkbox, Glyphs::Unicode, true) => "☑",
+            (Kind::Checkbox, Glyphs::Unicode, false) => "☐",
+            (Kind::Switch, Glyphs::Ascii, true) => "[--o]",
+            (Kind::Switch, Glyphs::Ascii, false) => "[o--]",
+            (Kind::Switch, Glyphs::Unicode, true) => "──●",
+            (Kind::Switch, Glyphs::Unicode, false) => "●──",
+        }
+    }
+}
+
+/// Message sent when a [`Toggle`] is flipped, holding the new value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Toggled(pub bool);
+
+/// Message sent when the selected option of a [`RadioGroup`] changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Selected(pub usize);
+
+/// A boolean value drawn as a checkbox or switch followed by a label
+///
+/// Toggled with `Enter` or `Space` while focused, or by clicking it.
+///
+/// # Example
+/// ```
+/// use clige::core::{data::Rect, event::Key};
+/// use clige::ui::{toggle::{Glyphs, Toggled}, Interactive, Toggle};
+///
+/// let mut sound = Toggle::switch("Sound").glyphs(Glyphs::Ascii);
+/// sound.set_focused(true);
+/// let message = sound.handle(&Key::Char(' ').into(), Rect::from([0, 0, 12, 1]));
+/// assert_eq!(message, Some(Toggled(true)));
+/// ```
+pub struct Toggle {
+    pub label: String,
+    kind: Kind,
+    glyphs: Glyphs,
+    value: bool,
+    styles: Styles,
+    on_change: Option<Box<dyn FnMut(bool)>>,
+    click: Click,
+    disabled: bool,
+    focused: bool,
+}
+
+impl Toggle {
+    pub fn new<S: Into<String>>(kind: Kind, label: S) -> Self {
+        Toggle {
+            label: label.into(),
+            kind,
+            glyphs: Glyphs::default(),
+            value: false,
+            styles: Styles::default(),
+            on_change: None,
+            click: Click::default(),
+            disabled: false,
+            focused: false,
+        }
+    }
+
+    pub fn checkbox<S: Into<String>>(label: S) -> Self {
+        Toggle::new(Kind::Checkbox, label)
+    }
+
+    pub fn switch<S: Into<String>>(label: S) -> Self {
+        Toggle::new(Kind::Switch, label)
+    }
+
+    pub fn glyphs(self, glyphs: Glyphs) -> Self {
+        Toggle { glyphs, ..self }
+    }
+
+    /// Set the starting value
+    pub fn checked(self, value: bool) -> Self {
+        Toggle { value, ..self }
+    }
+
+    /// Set the color used while the toggle is in the given state
+    pub fn style(mut self, state: State, color: Color) -> Self {
+        self.styles.set(state, color);
+        self
+    }
+
+    /// Take the colors for each state from a theme, using the `toggle` overrides
+    pub fn themed(self, theme: &Theme) -> Self {
+        Toggle {
+            styles: self.styles.themed(theme.states("toggle")),
+            ..self
+        }
+    }
+
+    /// Set the callback that is run with the new value each time the toggle changes
+    pub fn on_change<F: FnMut(bool) + 'static>(self, callback: F) -> Self {
+        Toggle {
+            on_change: Some(Box::new(callback)),
+            ..self
+        }
+    }
+
+    pub fn value(&self) -> bool {
+        self.value
+    }
+
+    /// Set the value without emitting a change
+    pub fn set_value(&mut self, value: bool) {
+        self.value = value;
+    }
+
+    /// Flip the value and notify the `on_change` callback
+    pub fn toggle(&mut self) -> Option<Toggled> {
+        if self.disabled {
+            return None;
+        }
+
+        self.value = !self.value;
+        if let Some(callback) = self.on_change.as_mut() {
+            callback(self.value);
+        }
+        Some(Toggled(self.value))
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+        if disabled {
+            self.focused = false;
+        }
+    }
+
+    pub fn state(&self) -> State {
+        if self.disabled {
+            State::Disabled
+        } else if self.click.pressed {
+            State::Pressed
+        } else if self.click.hovered {
+            State::Hovered
+        } else if self.focused {
+            State::Focused
+        } else {
+            State::Normal
+        }
+    }
+}
+
+impl Widget for Toggle {
+    fn render(&self, buffer: &mut PixelBuffer, area: Rect) {
+        let color = self.styles.get(&self.state()).cloned().unwrap_or_default();
+        let content = format!(
+            "{} {}",
+            self.kind.glyph(self.glyphs, self.value),
+            self.label
+        );
+
+        Text::new(Span::new(content, color))
+            .wrap(Wrap::None)
+            .overflow(Overflow::Ellipsis)
+            .render(buffer, area);
+    }
+}
+
+impl Interactive for Toggle {
+    type Message = Toggled;
+
+    fn handle(&mut self, event: &Event, area: Rect) -> Option<Toggled> {
+        if self.disabled {
+            return None;
+        }
+
+        let clicked = self.click.update(event, &area);
+        if clicked || (self.focused && is_activate(event)) {
+            return self.toggle();
+        }
+        None
+    }
+
+    fn focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused && !self.disabled;
+    }
+
+    fn focusable(&self) -> bool {
+        !self.disabled
+    }
+}
+
+/// A set of options where exactly one is selected, drawn one option per row
+///
+/// While focused, `Up` and `Down` move the selection. Clicking an option selects it.
+pub struct RadioGroup {
+    pub options: Vec<String>,
+    selected: usize,
+    glyphs: Glyphs,
+    styles: Styles,
+    on_change: Option<Box<dyn FnMut(usize)>>,
+    /// Row the left mouse button was pressed on
+    pressed: Option<usize>,
+    disabled: bool,
+    focused: bool,
+}
+
+impl RadioGroup {
+    pub fn new<S: Into<String>>(options: impl IntoIterator<Item = S>) -> Self {
+        RadioGroup {
+            options: options.into_iter().map(Into::into).collect(),
+            selected: 0,
+            glyphs: Glyphs::default(),
+            styles: Styles::default(),
+            on_change: None,
+            pressed: None,
+            disabled: false,
+            focused: false,
+        }
+    }
+
+    pub fn glyphs(self, glyphs: Glyphs) -> Self {
+        RadioGroup { glyphs, ..self }
+    }
+
+    /// Set the color used for the selected option while the group is in the given state
+    pub fn style(mut self, state: State, color: Color) -> Self {
+        self.styles.set(state, color);
+        self
+    }
+
+    /// Take the colors for each state from a theme, using the `radio` overrides
+    pub fn themed(self, theme: &Theme) -> Self {
+        RadioGroup {
+            styles: self.styles.themed(theme.states("radio")),
+            ..self
+        }
+    }
+
+    /// Set the callback that is run with the new index each time the selection changes
+    pub fn on_change<F: FnMut(usize) + 'static>(self, callback: F) -> Self {
+        RadioGroup {
+            on_change: Some(Box::new(callback)),
+            ..self
+        }
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Select an option and notify the `on_change` callback if the selection changed
+    pub fn select(&mut self, index: usize) -> Option<Selected> {
+        if self.disabled || index >= self.options.len() || index == self.selected {
+            return None;
+        }
+
+        self.selected = index;
+        if let Some(callback) = self.on_change.as_mut() {
+            callback(index);
+        }
+        Some(Selected(index))
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+        if disabled {
+            self.focused = false;
+        }
+    }
+
+    fn glyph(&self, selected: bool) -> &'static str {
+        match (self.glyphs, selected) {
+            (Glyphs::Ascii, true) => "(*)",
+            (Glyphs::Ascii, false) => "( )",
+            (Glyphs::Unicode, true) => "◉",
+            (Glyphs::Unicode, false) => "○",
+        }
+    }
+}
+
+impl Widget for RadioGroup {
+    fn render(&self, buffer: &mut PixelBuffer, area: Rect) {
+        let state = if self.disabled {
+            State::Disabled
+        } else if self.focused {
+            State::Focused
+        } else {
+            State::Normal
+        };
+
+        for (row, option) in self.options.iter().enumerate().take(area.height()) {
+            let selected = row == self.selected;
+            let color = match selected {
+                true => self.styles.get(&state),
+                false => self.styles.get(&State::Normal),
+            };
+
+            let y = area.top + row;
+            Text::new(Span::new(
+                format!("{} {}", self.glyph(selected), option),
+                color.cloned().unwrap_or_default(),
+            ))
+            .wrap(Wrap::None)
+            .overflow(Overflow::Ellipsis)
+            .render(buffer, Rect::from([area.left, y, area.right, y + 1]));
+        }
+    }
+}
+
+impl Interactive for RadioGroup {
+    type Message = Selected;
+
+    fn handle(&mut self, event: &Event, area: Rect) -> Option<Selected> {
+        if self.disabled {
+            return None;
+        }
+
+        match event {
+            Event::Mouse(mouse) => {
+                let row = area.contains(mouse.x, mouse.y).then(|| mouse.y - area.top);
+                match mouse.kind {
+                    MouseKind::Down(MouseButton::Left) => {
+                        self.pressed = row;
+                        None
+                    }
+                    // Only a press and release on the same option selects it
+                    MouseKind::Up(MouseButton::Left) => match self.pressed.take() {
+                        Some(pressed) if row == Some(pressed) => self.select(pressed),
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            }
+            Event::Key(key) if self.focused => match key.key {
+                Key::Up => self.select(self.selected.saturating_sub(1)),
+                Key::Down => self.select(self.selected + 1),
+                Key::Home => self.select(0),
+                Key::End => self.select(self.options.len().saturating_sub(1)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused && !self.disabled;
+    }
+
+    fn focusable(&self) -> bool {
+        !self.disabled && !self.options.is_empty()
+    }
+}
@@ -0,0 +1,38 @@
+use clige::physics::{Aabb, Body, Physics};
+
+fn empty(_x: i64, _y: i64) -> bool {
+    false
+}
+
+#[test]
+fn updates_run_whole_steps() {
+    let mut physics = Physics::new(0.1);
+    let mut bodies = [Body::new(Aabb::new(0., 0., 1., 1.)).velocity(10., 0.)];
+
+    assert_eq!(physics.update(0.25, &mut bodies, &empty), 2);
+    assert!((physics.alpha() - 0.5).abs() < 1e-9);
+    assert!((bodies[0].aabb.x - 2.).abs() < 1e-9);
+
+    let (x, _) = bodies[0].interpolated(physics.alpha());
+    assert!((x - 1.5).abs() < 1e-9);
+}
+
+#[test]
+fn slow_frames_are_capped() {
+    let mut physics = Physics::new(0.1).max_steps(3);
+    let mut bodies = [Body::new(Aabb::new(0., 0., 1., 1.))];
+
+    assert_eq!(physics.update(10., &mut bodies, &empty), 3);
+    assert_eq!(physics.alpha(), 1.);
+    assert_eq!(physics.update(0., &mut bodies, &empty), 1);
+}
+
+#[test]
+fn zero_steps_never_run() {
+    let mut physics = Physics::new(0.);
+    let mut bodies = [Body::new(Aabb::new(0., 0., 1., 1.)).velocity(1., 0.)];
+
+    assert_eq!(physics.update(1., &mut bodies, &empty), 0);
+    assert_eq!(physics.alpha(), 1.);
+    assert_eq!(bodies[0].position(), (0., 0.));
+}
@@ -0,0 +1,77 @@
+use clige::core::{
+    data::Rect,
+    event::{Event, Key, MouseButton, MouseEvent, MouseKind},
+};
+use clige::ui::{button::Pressed, Button, Interactive, State};
+
+const AREA: Rect = Rect {
+    left: 2,
+    top: 1,
+    right: 10,
+    bottom: 4,
+};
+
+fn mouse(kind: MouseKind, x: usize, y: usize) -> Event {
+    MouseEvent::new(kind, x, y).into()
+}
+
+fn down(x: usize, y: usize) -> Event {
+    mouse(MouseKind::Down(MouseButton::Left), x, y)
+}
+
+fn up(x: usize, y: usize) -> Event {
+    mouse(MouseKind::Up(MouseButton::Left), x, y)
+}
+
+#[test]
+fn clicking_inside_presses() {
+    let mut button = Button::new("Go");
+    assert_eq!(button.handle(&down(3, 2), AREA), None);
+    assert_eq!(button.state(), State::Pressed);
+    assert_eq!(button.handle(&up(4, 2), AREA), Some(Pressed));
+    assert_eq!(button.state(), State::Hovered);
+}
+
+#[test]
+fn releasing_outside_cancels() {
+    let mut button = Button::new("Go");
+    button.handle(&down(3, 2), AREA);
+    assert_eq!(button.handle(&up(20, 2), AREA), None);
+    assert_eq!(button.state(), State::Normal);
+}
+
+#[test]
+fn pressing_outside_doesnt_press() {
+    let mut button = Button::new("Go");
+    button.handle(&down(3, 2), AREA);
+    button.handle(&down(0, 0), AREA);
+    assert_eq!(button.state(), State::Normal);
+    // The release that follows a press elsewhere isn't a click
+    assert_eq!(button.handle(&up(3, 2), AREA), None);
+}
+
+#[test]
+fn keys_activate_only_while_focused() {
+    let mut button = Button::new("Go");
+    assert_eq!(button.handle(&Key::Enter.into(), AREA), None);
+
+    button.set_focused(true);
+    assert_eq!(button.handle(&Key::Enter.into(), AREA), Some(Pressed));
+    assert_eq!(button.state(), State::Pressed);
+    assert_eq!(button.handle(&Key::Char(' ').into(), AREA), Some(Pressed));
+    assert_eq!(button.handle(&Key::Char('x').into(), AREA), None);
+    assert_eq!(button.state(), State::Focused);
+}
+
+#[test]
+fn disabled_buttons_ignore_input() {
+    let mut button = Button::new("Go");
+    button.set_focused(true);
+    button.set_disabled(true);
+
+    assert!(!button.focused());
+    assert_eq!(button.handle(&down(3, 2), AREA), None);
+    assert_eq!(button.handle(&up(3, 2), AREA), None);
+    assert_eq!(button.press(), None);
+    assert_eq!(button.state(), State::Disabled);
+}
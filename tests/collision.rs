@@ -0,0 +1,38 @@
+//! Boxes stopped by tiles must end up exactly on the tile's edge, or resting boxes
+//! drift off the cell boundary and stop counting as touching it.
+
+use clige::physics::collision::{sweep, Aabb};
+
+#[test]
+fn falling_boxes_rest_on_the_floor() {
+    let floor = |_x: i64, y: i64| y == 10;
+
+    for (y, height) in [(0.1, 0.7), (0.2, 0.6), (0.3, 0.9), (2.3, 0.1)] {
+        let result = sweep(&Aabb::new(0., y, 1., height), 0., 20., &floor);
+        assert_eq!(
+            result.aabb.bottom(),
+            10.,
+            "box at {} of height {}",
+            y,
+            height
+        );
+    }
+}
+
+#[test]
+fn rising_boxes_stop_under_the_ceiling() {
+    let ceiling = |_x: i64, y: i64| y == -5;
+
+    for y in [0.1, 0.35, 5.7] {
+        let result = sweep(&Aabb::new(0., y, 1., 1.), 0., -20., &ceiling);
+        assert_eq!(result.aabb.top(), -4., "box at {}", y);
+    }
+}
+
+#[test]
+fn walls_stop_boxes_on_their_edge() {
+    let wall = |x: i64, _y: i64| x == 10;
+
+    let result = sweep(&Aabb::new(0.1, 0., 0.7, 1.), 20., 0., &wall);
+    assert_eq!(result.aabb.right(), 10.);
+}
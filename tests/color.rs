@@ -0,0 +1,77 @@
+//! Pins the escape sequences colors write, since every widget and example is drawn
+//! with them.
+
+use clige::core::color::{Color, Context, Rgb, SystemColor, Tone};
+
+#[test]
+fn default_resets_the_terminal() {
+    assert_eq!(Color::default().to_string(), "\x1b[0m");
+}
+
+#[test]
+fn xterm_colors() {
+    assert_eq!(
+        Color::xterm(208, Context::Foreground).to_string(),
+        "\x1b[38;5;208m"
+    );
+    assert_eq!(
+        Color::xterm(208, Context::Background).to_string(),
+        "\x1b[48;5;208m"
+    );
+    assert_eq!(
+        Color::xterm(208, Context::Solid).to_string(),
+        "\x1b[38;5;208;48;5;208m"
+    );
+}
+
+#[test]
+fn rgb_colors() {
+    assert_eq!(
+        Color::rgb(1, 2, 3, Context::Foreground).to_string(),
+        "\x1b[38;2;1;2;3m"
+    );
+    assert_eq!(
+        Color::rgb(1, 2, 3, Context::Background).to_string(),
+        "\x1b[48;2;1;2;3m"
+    );
+    assert_eq!(
+        Color::rgb(1, 2, 3, Context::Solid).to_string(),
+        "\x1b[38;2;1;2;3;48;2;1;2;3m"
+    );
+}
+
+#[test]
+fn system_colors() {
+    assert_eq!(
+        Color::system(SystemColor::Red, Context::Foreground).to_string(),
+        "\x1b[31m"
+    );
+    assert_eq!(
+        Color::system(SystemColor::Blue, Context::Background).to_string(),
+        "\x1b[44m"
+    );
+    assert_eq!(
+        Color::system(SystemColor::White, Context::Solid).to_string(),
+        "\x1b[37;47m"
+    );
+}
+
+#[test]
+fn tones_parse() {
+    assert_eq!(Tone::parse("#FF8700"), Some(Tone::Rgb(Rgb(255, 135, 0))));
+    assert_eq!(Tone::parse("208"), Some(Tone::Xterm(208)));
+    assert_eq!(
+        Tone::parse("bright-red"),
+        Some(Tone::Bright(SystemColor::Red))
+    );
+    assert_eq!(Tone::parse(" blue "), Some(Tone::System(SystemColor::Blue)));
+}
+
+#[test]
+fn malformed_tones_are_rejected() {
+    for value in [
+        "#ff870", "#ff87000", "#gg8700", "#+f+f+f", "#aé€", "#ffé€", "256", "pink",
+    ] {
+        assert_eq!(Tone::parse(value), None, "{:?}", value);
+    }
+}
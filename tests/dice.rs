@@ -0,0 +1,50 @@
+use clige::gen::{Dice, Random};
+
+#[test]
+fn parses_and_bounds_expressions() {
+    let dice: Dice = "2d8 - 1d4 + 3".parse().unwrap();
+    assert_eq!((dice.min(), dice.max()), (1, 18));
+    assert_eq!(dice.to_string(), "2d8-1d4+3");
+
+    let mut rng = Random::from(3);
+    for _ in 0..100 {
+        assert!((1..=18).contains(&rng.roll(&dice)));
+    }
+}
+
+#[test]
+fn rejects_too_many_dice() {
+    assert!("1000000000d6".parse::<Dice>().is_err());
+    assert!("4294967295d4294967295+4294967295d4294967295"
+        .parse::<Dice>()
+        .is_err());
+    assert!("600d6+600d6".parse::<Dice>().is_err());
+
+    let dice: Dice = "1000d4294967295".parse().unwrap();
+    assert_eq!(dice.max(), 1000 * 4294967295);
+    assert_eq!(Dice::new(u32::MAX, 6, 0).max(), 6000);
+}
+
+#[test]
+fn rejects_overflowing_numbers() {
+    assert!("9223372036854775807+1".parse::<Dice>().is_err());
+    assert!("-9223372036854775807-2".parse::<Dice>().is_err());
+
+    let dice: Dice = "1d6+9223372036854775807".parse().unwrap();
+    assert_eq!(dice.max(), i64::MAX);
+    assert_eq!(Dice::new(1, 6, i64::MIN).min(), i64::MIN + 1);
+}
+
+#[test]
+fn parses_the_full_range_of_numbers() {
+    let lowest: Dice = "-9223372036854775808".parse().unwrap();
+    assert_eq!((lowest.min(), lowest.max()), (i64::MIN, i64::MIN));
+    assert_eq!(lowest.to_string(), "-9223372036854775808");
+    assert!("9223372036854775808".parse::<Dice>().is_err());
+    assert!("-9223372036854775809".parse::<Dice>().is_err());
+
+    let dice: Dice = "-3+d6-2".parse().unwrap();
+    assert_eq!((dice.min(), dice.max()), (-4, 1));
+    assert!("--3".parse::<Dice>().is_err());
+    assert!("3+".parse::<Dice>().is_err());
+}
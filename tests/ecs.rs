@@ -0,0 +1,156 @@
+use std::cell::RefCell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+
+use clige::ecs::{Schedule, Stage, Time, World};
+
+#[derive(Debug, PartialEq)]
+struct Position(i32);
+struct Velocity(i32);
+struct Frozen;
+
+#[test]
+fn despawned_indexes_are_reused_with_a_new_generation() {
+    let mut world = World::new();
+    let first = world.spawn();
+    world.insert(first, Position(1)).unwrap();
+    assert!(world.despawn(first));
+    assert!(!world.despawn(first));
+
+    let second = world.spawn();
+    assert_eq!(second.index(), first.index());
+    assert_ne!(second.generation(), first.generation());
+    assert!(!world.is_alive(first));
+    assert!(world.is_alive(second));
+
+    // The stale handle can't reach the entity that replaced it
+    assert_eq!(world.get::<Position>(second), None);
+    assert!(world.insert(first, Position(2)).is_err());
+    assert_eq!(world.get::<Position>(first), None);
+    assert_eq!(world.len(), 1);
+}
+
+#[test]
+fn queries_filter_by_other_components() {
+    let mut world = World::new();
+    let moving = world.spawn();
+    let frozen = world.spawn();
+    let still = world.spawn();
+    for (entity, x) in [(moving, 0), (frozen, 10), (still, 20)] {
+        world.insert(entity, Position(x)).unwrap();
+    }
+    world.insert(moving, Velocity(1)).unwrap();
+    world.insert(frozen, Velocity(1)).unwrap();
+    world.insert(frozen, Frozen).unwrap();
+
+    assert_eq!(world.query::<Position>().count(), 3);
+    assert_eq!(
+        world.query::<Position>().with::<Velocity>().entities(),
+        [moving, frozen]
+    );
+    assert_eq!(
+        world
+            .query::<Position>()
+            .with::<Velocity>()
+            .without::<Frozen>()
+            .single()
+            .map(|(entity, _)| entity),
+        Some(moving)
+    );
+
+    world
+        .query_mut::<Position>()
+        .with::<Velocity>()
+        .without::<Frozen>()
+        .for_each(|entity, position, world| {
+            position.0 += world.get::<Velocity>(entity).unwrap().0;
+        });
+    assert_eq!(world.get::<Position>(moving), Some(&Position(1)));
+    assert_eq!(world.get::<Position>(frozen), Some(&Position(10)));
+}
+
+#[test]
+fn changed_filters_see_changes_since_the_last_run() {
+    for mut world in [World::new(), World::default()] {
+        let entity = world.spawn();
+        let other = world.spawn();
+        world.insert(entity, Position(0)).unwrap();
+        world.insert(other, Position(0)).unwrap();
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let log = seen.clone();
+        let mut schedule = Schedule::new().system(Stage::Update, "watch", move |world| {
+            log.borrow_mut()
+                .push(world.query::<Position>().changed::<Position>().count());
+        });
+
+        // Components inserted before the first run count as changed
+        schedule.run(&mut world, 1.);
+        schedule.run(&mut world, 1.);
+        world.get_mut::<Position>(other).unwrap().0 += 1;
+        schedule.run(&mut world, 1.);
+        assert_eq!(*seen.borrow(), [2, 0, 1]);
+    }
+
+    let mut world = World::new();
+    let entity = world.spawn();
+    world.insert(entity, Position(0)).unwrap();
+    assert!(world.is_changed::<Position>(entity));
+    world.clear_changes();
+    assert!(!world.is_changed::<Position>(entity));
+}
+
+#[test]
+fn resources_are_stored_by_type() {
+    let mut world = World::new();
+    assert!(!world.has_resource::<u32>());
+    assert_eq!(world.insert_resource(1u32), None);
+    assert_eq!(world.insert_resource(2u32), Some(1));
+    *world.resource_mut::<u32>().unwrap() += 1;
+    assert_eq!(world.resource::<u32>(), Some(&3));
+    assert_eq!(world.resource::<i32>(), None);
+    assert_eq!(world.remove_resource::<u32>(), Some(3));
+    assert!(!world.has_resource::<u32>());
+}
+
+#[test]
+fn update_systems_run_before_render_systems() {
+    let order = Rc::new(RefCell::new(Vec::new()));
+    let system = |name: &'static str| {
+        let order = order.clone();
+        move |_: &mut World| order.borrow_mut().push(name)
+    };
+    let mut schedule = Schedule::new()
+        .system(Stage::Render, "draw", system("draw"))
+        .system(Stage::Update, "move", system("move"))
+        .system(Stage::Render, "hud", system("hud"))
+        .system(Stage::Update, "collide", system("collide"));
+    assert_eq!(schedule.systems(Stage::Update), ["move", "collide"]);
+
+    let mut world = World::new();
+    schedule.run(&mut world, 0.5);
+    assert_eq!(*order.borrow(), ["move", "collide", "draw", "hud"]);
+
+    order.borrow_mut().clear();
+    assert!(schedule.remove("move"));
+    schedule.run(&mut world, 0.5);
+    assert_eq!(*order.borrow(), ["collide", "draw", "hud"]);
+
+    let time = world.resource::<Time>().unwrap();
+    assert_eq!((time.delta, time.elapsed, time.frame), (0.5, 1., 1));
+}
+
+#[test]
+fn components_survive_a_panicking_query() {
+    let mut world = World::new();
+    let entity = world.spawn();
+    world.insert(entity, Position(1)).unwrap();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        world
+            .query_mut::<Position>()
+            .for_each(|_, _, _| panic!("system failed"));
+    }));
+    assert!(result.is_err());
+    assert_eq!(world.get::<Position>(entity), Some(&Position(1)));
+}
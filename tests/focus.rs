@@ -0,0 +1,158 @@
+use clige::core::{
+    data::Rect,
+    event::{Event, Key, KeyEvent, Modifiers, MouseButton, MouseEvent, MouseKind},
+};
+use clige::ui::{
+    focus::{FocusChange, Order},
+    FocusManager,
+};
+
+fn row(y: usize) -> Rect {
+    Rect::from([0, y, 10, y + 1])
+}
+
+fn tab(focus: &mut FocusManager<&'static str>) -> Option<&'static str> {
+    focus.handle(&Key::Tab.into());
+    focus.focused()
+}
+
+fn back_tab(focus: &mut FocusManager<&'static str>) -> Option<&'static str> {
+    focus.handle(&KeyEvent::new(Key::Tab, Modifiers::SHIFT).into());
+    focus.focused()
+}
+
+fn three() -> FocusManager<&'static str> {
+    let mut focus = FocusManager::new();
+    focus.add("a", row(0));
+    focus.add("b", row(1));
+    focus.add("c", row(2));
+    focus
+}
+
+#[test]
+fn tab_wraps_around() {
+    let mut focus = three();
+    assert_eq!(focus.focused(), Some("a"));
+    assert_eq!(tab(&mut focus), Some("b"));
+    assert_eq!(tab(&mut focus), Some("c"));
+    assert_eq!(tab(&mut focus), Some("a"));
+
+    assert_eq!(back_tab(&mut focus), Some("c"));
+    focus.handle(&Key::BackTab.into());
+    assert_eq!(focus.focused(), Some("b"));
+}
+
+#[test]
+fn explicit_indexes_come_first() {
+    let mut focus = three();
+    focus.set_index("c", Some(0));
+    assert_eq!(focus.tab_order(), ["c", "a", "b"]);
+
+    let mut reading = FocusManager::new().order(Order::Reading);
+    reading.add("bottom", row(5));
+    reading.add("right", Rect::from([20, 0, 30, 1]));
+    reading.add("left", row(0));
+    assert_eq!(reading.tab_order(), ["left", "right", "bottom"]);
+}
+
+#[test]
+fn disabled_elements_are_skipped() {
+    let mut focus = three();
+    focus.set_enabled("b", false);
+    assert_eq!(tab(&mut focus), Some("c"));
+    assert_eq!(tab(&mut focus), Some("a"));
+
+    // Disabling the focused element moves focus on
+    assert_eq!(
+        focus.set_enabled("a", false),
+        Some(FocusChange {
+            from: Some("a"),
+            to: Some("c")
+        })
+    );
+    assert_eq!(focus.focus("b"), None);
+
+    // With nothing left to focus, focus is cleared
+    focus.set_enabled("c", false);
+    assert_eq!(focus.focused(), None);
+    assert_eq!(tab(&mut focus), None);
+
+    focus.set_enabled("b", true);
+    assert_eq!(tab(&mut focus), Some("b"));
+}
+
+#[test]
+fn scopes_limit_navigation_and_restore_focus() {
+    let mut focus = three();
+    tab(&mut focus);
+
+    focus.push_scope();
+    assert_eq!(focus.focused(), None);
+    focus.add("ok", row(10));
+    focus.add("cancel", row(11));
+    assert_eq!(focus.focused(), Some("ok"));
+    assert_eq!(tab(&mut focus), Some("cancel"));
+    assert_eq!(tab(&mut focus), Some("ok"));
+    // Elements outside of the scope can't be focused, even by clicking
+    assert_eq!(focus.focus("a"), None);
+    let click = MouseEvent::new(MouseKind::Down(MouseButton::Left), 1, 0);
+    assert_eq!(focus.handle(&Event::from(click)), None);
+
+    assert_eq!(
+        focus.pop_scope(),
+        Some(FocusChange {
+            from: Some("ok"),
+            to: Some("b")
+        })
+    );
+    assert_eq!(focus.tab_order(), ["a", "b", "c"]);
+    // The bottom scope can't be popped
+    assert_eq!(focus.pop_scope(), None);
+}
+
+#[test]
+fn removing_the_focused_element_moves_focus_on() {
+    let mut focus = three();
+    focus.remove("a");
+    assert_eq!(focus.focused(), Some("b"));
+    focus.remove("b");
+    focus.remove("c");
+    assert_eq!(focus.focused(), None);
+}
+
+#[test]
+fn clicks_and_arrows_move_focus() {
+    let mut focus = FocusManager::new().arrows(true);
+    focus.add("top", Rect::from([10, 0, 20, 1]));
+    focus.add("left", Rect::from([5, 10, 15, 11]));
+    focus.add("right", Rect::from([20, 10, 30, 11]));
+
+    focus.handle(&Key::Down.into());
+    assert_eq!(focus.focused(), Some("left"));
+    focus.handle(&Key::Right.into());
+    assert_eq!(focus.focused(), Some("right"));
+    assert_eq!(focus.handle(&Key::Right.into()), None);
+
+    let click = MouseEvent::new(MouseKind::Down(MouseButton::Left), 12, 0);
+    focus.handle(&Event::from(click));
+    assert_eq!(focus.focused(), Some("top"));
+
+    // Arrows are ignored unless enabled
+    let mut focus = three();
+    assert_eq!(focus.handle(&Key::Down.into()), None);
+}
+
+#[test]
+fn disabling_the_focused_element_moves_to_the_next_one() {
+    let mut focus = three();
+    tab(&mut focus);
+    assert_eq!(focus.focused(), Some("b"));
+    assert_eq!(
+        focus.set_enabled("b", false),
+        Some(FocusChange {
+            from: Some("b"),
+            to: Some("c")
+        })
+    );
+    assert_eq!(back_tab(&mut focus), Some("a"));
+}
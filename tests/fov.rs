@@ -0,0 +1,176 @@
+use clige::grid::{
+    fov::{fov, line_of_sight, Algorithm},
+    Grid,
+};
+
+const ALGORITHMS: [Algorithm; 2] = [Algorithm::Shadowcasting, Algorithm::Permissive];
+
+/// Maps an offset from the viewer in one octant onto another
+type Transform = fn(isize, isize) -> (isize, isize);
+
+/// The eight reflections and rotations that map one octant onto each of the others
+const OCTANTS: [Transform; 8] = [
+    |x, y| (x, y),
+    |x, y| (y, x),
+    |x, y| (-y, x),
+    |x, y| (-x, y),
+    |x, y| (-x, -y),
+    |x, y| (-y, -x),
+    |x, y| (y, -x),
+    |x, y| (x, -y),
+];
+
+fn at(origin: (usize, usize), (dx, dy): (isize, isize)) -> (usize, usize) {
+    (
+        (origin.0 as isize + dx) as usize,
+        (origin.1 as isize + dy) as usize,
+    )
+}
+
+#[test]
+fn every_octant_sees_the_same_shape() {
+    // The same pillars copied into every octant around the viewer
+    let origin = (7, 7);
+    let mut opacity = Grid::new(15, 15, false);
+    for pillar in [(3, 1), (5, 3), (6, 0), (2, 2)] {
+        for octant in OCTANTS {
+            opacity[at(origin, octant(pillar.0, pillar.1))] = true;
+        }
+    }
+
+    for algorithm in ALGORITHMS {
+        let visible = fov(&opacity, origin, 7, algorithm);
+        assert!(visible[(14, 7)] || visible[(13, 7)]);
+        for dy in -7..=7 {
+            for dx in -7..=7 {
+                let expected = visible[at(origin, (dx, dy))];
+                for octant in OCTANTS {
+                    assert_eq!(
+                        visible[at(origin, octant(dx, dy))],
+                        expected,
+                        "{:?} at offset {:?}",
+                        algorithm,
+                        octant(dx, dy)
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn walls_block_sight_but_are_lit() {
+    let opacity = Grid::from_fn(12, 7, |x, _| x == 6);
+    for algorithm in ALGORITHMS {
+        let visible = fov(&opacity, (2, 3), 20, algorithm);
+        for y in 0..7 {
+            assert!(visible[(6, y)], "{:?} wall at y = {}", algorithm, y);
+            for x in 0..6 {
+                assert!(visible[(x, y)]);
+            }
+            for x in 7..12 {
+                assert!(
+                    !visible[(x, y)],
+                    "{:?} behind wall at {:?}",
+                    algorithm,
+                    (x, y)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn radius_limits_sight() {
+    let opacity = Grid::new(21, 21, false);
+    for algorithm in ALGORITHMS {
+        let visible = fov(&opacity, (10, 10), 4, algorithm);
+        assert!(visible[(14, 10)]);
+        assert!(!visible[(15, 10)]);
+        assert!(visible[(13, 13)]);
+        assert!(!visible[(14, 14)]);
+        assert!(!fov(&opacity, (30, 30), 4, algorithm)
+            .cells()
+            .contains(&true));
+    }
+}
+
+#[test]
+fn permissive_sees_past_the_edges_of_a_pillar() {
+    let opacity = Grid::from_fn(9, 9, |x, y| (x, y) == (5, 4));
+    let shadowcast = fov(&opacity, (2, 4), 20, Algorithm::Shadowcasting);
+    let permissive = fov(&opacity, (2, 4), 20, Algorithm::Permissive);
+
+    for x in 6..9 {
+        assert!(!shadowcast[(x, 4)]);
+        assert!(permissive[(x, 4)]);
+    }
+
+    // Everything shadowcasting sees, the permissive field sees too
+    for y in 0..9 {
+        for x in 0..9 {
+            assert!(!shadowcast[(x, y)] || permissive[(x, y)]);
+        }
+    }
+}
+
+#[test]
+fn permissive_visibility_is_mutual() {
+    let opacity = Grid::from_fn(13, 13, |x, y| (x * 7 + y * 13) % 11 == 0);
+    let origin = (6, 5);
+    let visible = fov(&opacity, origin, 20, Algorithm::Permissive);
+    for y in 0..13 {
+        for x in 0..13 {
+            if opacity[(x, y)] {
+                continue;
+            }
+            let back = fov(&opacity, (x, y), 20, Algorithm::Permissive);
+            assert_eq!(back[origin], visible[(x, y)], "from {:?}", (x, y));
+        }
+    }
+}
+
+#[test]
+fn line_of_sight_agrees_with_shadowcasting() {
+    let opacity = Grid::from_fn(21, 21, |x, y| {
+        (x * 7 + y * 13) % 11 == 0 && (x, y) != (10, 10)
+    });
+    let origin = (10, 10);
+    let visible = fov(&opacity, origin, 30, Algorithm::Shadowcasting);
+
+    // Straight along the axes and diagonals both follow the same cells
+    for octant in OCTANTS {
+        for distance in 1..=10 {
+            let cell = at(origin, octant(distance, 0));
+            if opacity[cell] {
+                continue;
+            }
+            assert_eq!(
+                line_of_sight(&opacity, origin, cell),
+                visible[cell],
+                "to {:?}",
+                cell
+            );
+            assert_eq!(line_of_sight(&opacity, cell, origin), visible[cell]);
+        }
+        for distance in 1..=10 {
+            let (dx, dy) = octant(distance, distance);
+            let cell = at(origin, (dx, dy));
+            if !opacity[cell] {
+                assert_eq!(line_of_sight(&opacity, origin, cell), visible[cell]);
+            }
+        }
+    }
+
+    // In an open room and behind a wall, every cell agrees
+    for opacity in [Grid::new(15, 9, false), Grid::from_fn(15, 9, |x, _| x == 9)] {
+        let visible = fov(&opacity, (4, 4), 30, Algorithm::Shadowcasting);
+        for y in 0..9 {
+            for x in 0..15 {
+                if !opacity[(x, y)] {
+                    assert_eq!(line_of_sight(&opacity, (4, 4), (x, y)), visible[(x, y)]);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use clige::core::{
+    buffer::{Buffer, PixelBuffer},
+    data::Rect,
+};
+use clige::ui::{
+    gauge::{Frames, Label},
+    Gauge, Orientation, Spinner, Widget,
+};
+
+fn render<W: Widget>(widget: &W, width: usize, height: usize) -> String {
+    let mut buffer = PixelBuffer::new(width, height);
+    widget.render(&mut buffer, Rect::from([0, 0, width, height]));
+    buffer.pixels.iter().map(|p| p.value).collect()
+}
+
+#[test]
+fn bars_fill_in_eighths() {
+    assert_eq!(render(&Gauge::new(0.), 4, 1), "    ");
+    assert_eq!(render(&Gauge::new(0.5), 4, 1), "██  ");
+    // 0.3 of 32 eighths rounds to 10, a full cell and two eighths
+    assert_eq!(render(&Gauge::new(0.3), 4, 1), "█▎  ");
+    assert_eq!(render(&Gauge::new(7.), 4, 1), "████");
+
+    let vertical = Gauge::new(0.5).orientation(Orientation::Vertical);
+    assert_eq!(render(&vertical, 1, 4), "  ██");
+}
+
+#[test]
+fn values_are_clamped() {
+    let mut gauge = Gauge::new(-1.);
+    assert_eq!(gauge.value(), 0.);
+    gauge.set_progress(3, 4);
+    assert_eq!(gauge.value(), 0.75);
+    gauge.set_progress(5, 4);
+    assert_eq!(gauge.value(), 1.);
+    // Nothing to do counts as finished
+    gauge.set_progress(0, 0);
+    assert_eq!(gauge.value(), 1.);
+}
+
+#[test]
+fn labels_are_centered_over_the_bar() {
+    let gauge = Gauge::new(0.42).label(Label::Percent);
+    assert_eq!(render(&gauge, 9, 1), "███42%   ");
+
+    let loading = Gauge::indeterminate().label(Label::Percent);
+    assert!(!render(&loading, 9, 1).contains('%'));
+}
+
+#[test]
+fn indeterminate_gauges_bounce() {
+    let mut gauge = Gauge::indeterminate();
+    let mut frames = Vec::new();
+    for _ in 0..6 {
+        frames.push(render(&gauge, 4, 1));
+        gauge.tick();
+    }
+    assert_eq!(frames, ["█   ", " █  ", "  █ ", "   █", "  █ ", " █  "]);
+}
+
+#[test]
+fn spinners_cycle_through_frames() {
+    let mut spinner = Spinner::new("Loading").frames(Frames::Ascii);
+    assert_eq!(render(&spinner, 10, 1), "| Loading ");
+    for _ in 0..4 {
+        spinner.tick();
+    }
+    assert_eq!(render(&spinner, 10, 1), "| Loading ");
+    spinner.tick();
+    assert_eq!(render(&spinner, 1, 1), "/");
+}
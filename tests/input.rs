@@ -0,0 +1,172 @@
+use clige::core::{
+    buffer::{Buffer, PixelBuffer},
+    data::{Pixel, Rect},
+    event::{Event, Key, KeyEvent, Modifiers, MouseButton, MouseEvent, MouseKind},
+};
+use clige::ui::{input::Message, Interactive, TextInput, Widget};
+
+const AREA: Rect = Rect {
+    left: 0,
+    top: 0,
+    right: 10,
+    bottom: 3,
+};
+
+fn input(value: &str) -> TextInput {
+    let mut input = TextInput::new();
+    input.set_value(value);
+    input.set_focused(true);
+    input
+}
+
+fn press(input: &mut TextInput, key: Key) -> Option<Message> {
+    input.handle(&key.into(), AREA)
+}
+
+fn press_with(input: &mut TextInput, key: Key, modifiers: Modifiers) -> Option<Message> {
+    input.handle(&KeyEvent::new(key, modifiers).into(), AREA)
+}
+
+fn click(input: &mut TextInput, kind: MouseKind, x: usize, y: usize) {
+    input.handle(&Event::from(MouseEvent::new(kind, x, y)), AREA);
+}
+
+#[test]
+fn cursor_moves_by_character_over_wide_characters() {
+    let mut input = input("a世界b");
+    assert_eq!(input.cursor(), 4);
+
+    press(&mut input, Key::Left);
+    press(&mut input, Key::Left);
+    assert_eq!(input.cursor(), 2);
+    press(&mut input, Key::Backspace);
+    assert_eq!(input.value(), "a界b");
+    assert_eq!(input.cursor(), 1);
+}
+
+#[test]
+fn wide_characters_render_with_continuation_cells() {
+    let input = input("a世b");
+    let mut buffer = PixelBuffer::new(10, 1);
+    input.render(&mut buffer, Rect::from([0, 0, 10, 1]));
+
+    let values: Vec<char> = buffer.pixels[..5].iter().map(|p| p.value).collect();
+    assert_eq!(values, ['a', '世', Pixel::CONTINUATION, 'b', ' ']);
+}
+
+#[test]
+fn clicking_either_half_of_a_wide_character_puts_the_cursor_before_it() {
+    let mut input = input("a世b");
+    click(&mut input, MouseKind::Down(MouseButton::Left), 1, 0);
+    assert_eq!(input.cursor(), 1);
+    click(&mut input, MouseKind::Down(MouseButton::Left), 2, 0);
+    assert_eq!(input.cursor(), 1);
+    click(&mut input, MouseKind::Down(MouseButton::Left), 3, 0);
+    assert_eq!(input.cursor(), 2);
+}
+
+#[test]
+fn vertical_movement_keeps_the_display_column() {
+    let mut input = TextInput::new().multiline(true);
+    input.set_value("世界\nabcd");
+    input.set_focused(true);
+
+    press(&mut input, Key::Home);
+    press(&mut input, Key::Right);
+    press(&mut input, Key::Right);
+    // Column 2 of the first line is after the first wide character
+    press(&mut input, Key::Up);
+    assert_eq!(input.cursor(), 1);
+    press(&mut input, Key::Down);
+    assert_eq!(input.cursor(), 5);
+}
+
+#[test]
+fn shift_extends_the_selection() {
+    let mut input = input("hello world");
+
+    press_with(&mut input, Key::Left, Modifiers::SHIFT);
+    press_with(&mut input, Key::Left, Modifiers::SHIFT);
+    assert_eq!(input.selection(), Some((9, 11)));
+    assert_eq!(input.selected().as_deref(), Some("ld"));
+
+    // Moving without shift collapses the selection to its start
+    press(&mut input, Key::Left);
+    assert_eq!((input.selection(), input.cursor()), (None, 9));
+
+    let ctrl_shift = Modifiers {
+        shift: true,
+        ..Modifiers::CTRL
+    };
+    press_with(&mut input, Key::Left, ctrl_shift);
+    assert_eq!(input.selected().as_deref(), Some("wor"));
+
+    assert_eq!(
+        press(&mut input, Key::Char('W')),
+        Some(Message::Changed("hello Wld".to_string()))
+    );
+}
+
+#[test]
+fn dragging_selects() {
+    let mut input = input("hello world");
+    click(&mut input, MouseKind::Down(MouseButton::Left), 1, 0);
+    click(&mut input, MouseKind::Drag(MouseButton::Left), 4, 0);
+    click(&mut input, MouseKind::Up(MouseButton::Left), 4, 0);
+    assert_eq!(input.selected().as_deref(), Some("ell"));
+
+    press_with(&mut input, Key::Char('a'), Modifiers::CTRL);
+    assert_eq!(input.selection(), Some((0, 11)));
+    press(&mut input, Key::Delete);
+    assert_eq!(input.value(), "");
+}
+
+#[test]
+fn deleting_at_the_ends_does_nothing() {
+    let mut input = input("ab");
+    assert_eq!(press(&mut input, Key::Delete), None);
+
+    press(&mut input, Key::Home);
+    assert_eq!(press(&mut input, Key::Backspace), None);
+    assert_eq!(
+        press_with(&mut input, Key::Backspace, Modifiers::CTRL),
+        None
+    );
+    press(&mut input, Key::Left);
+    assert_eq!(input.cursor(), 0);
+
+    assert_eq!(
+        press(&mut input, Key::Delete),
+        Some(Message::Changed("b".to_string()))
+    );
+    press(&mut input, Key::End);
+    press(&mut input, Key::Right);
+    assert_eq!(input.cursor(), 1);
+}
+
+#[test]
+fn max_length_drops_extra_characters() {
+    let mut input = TextInput::new().max_length(2);
+    input.set_focused(true);
+    press(&mut input, Key::Char('a'));
+    press(&mut input, Key::Char('b'));
+    assert_eq!(press(&mut input, Key::Char('c')), None);
+    assert_eq!(input.value(), "ab");
+}
+
+#[test]
+fn long_content_scrolls_to_keep_the_cursor_visible() {
+    let mut input = input("0123456789abc");
+    let area = Rect::from([0, 0, 5, 1]);
+    input.handle(&Key::End.into(), area);
+
+    let mut buffer = PixelBuffer::new(5, 1);
+    input.render(&mut buffer, area);
+    let shown: String = buffer.pixels.iter().map(|p| p.value).collect();
+    assert_eq!(shown, "9abc ");
+
+    input.handle(&Key::Home.into(), area);
+    input.render(&mut buffer, area);
+    let shown: String = buffer.pixels.iter().map(|p| p.value).collect();
+    assert_eq!(shown, "01234");
+}
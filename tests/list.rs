@@ -0,0 +1,186 @@
+use clige::core::{
+    buffer::{Buffer, PixelBuffer},
+    data::Rect,
+    event::{Event, Key, MouseButton, MouseEvent, MouseKind},
+};
+use clige::ui::{
+    list::{Item, List, Message},
+    Interactive, Widget,
+};
+
+const AREA: Rect = Rect {
+    left: 0,
+    top: 0,
+    right: 6,
+    bottom: 3,
+};
+
+fn numbers(count: usize) -> List {
+    let mut list = List::new((0..count).map(|n| format!("item{}", n)));
+    list.set_focused(true);
+    list
+}
+
+fn press(list: &mut List, key: Key) -> Option<Message> {
+    list.handle(&key.into(), AREA)
+}
+
+/// The first word of each visible row
+fn rows(list: &List) -> Vec<String> {
+    let mut buffer = PixelBuffer::new(AREA.width(), AREA.height());
+    list.render(&mut buffer, AREA);
+    buffer
+        .pixels
+        .chunks(AREA.width())
+        .map(|row| {
+            let row: String = row.iter().map(|p| p.value).collect();
+            row.split([' ', '│', '█']).next().unwrap().to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn scrolls_to_keep_the_highlight_visible() {
+    let mut list = numbers(10);
+    assert_eq!(rows(&list), ["item0", "item1", "item2"]);
+
+    for _ in 0..4 {
+        press(&mut list, Key::Down);
+    }
+    assert_eq!(list.selected(), [4]);
+    assert_eq!(rows(&list), ["item2", "item3", "item4"]);
+
+    // Moving back up only scrolls once the highlight leaves the top
+    press(&mut list, Key::Up);
+    press(&mut list, Key::Up);
+    assert_eq!(rows(&list), ["item2", "item3", "item4"]);
+    press(&mut list, Key::Up);
+    assert_eq!(rows(&list), ["item1", "item2", "item3"]);
+
+    press(&mut list, Key::End);
+    assert_eq!(rows(&list), ["item7", "item8", "item9"]);
+    press(&mut list, Key::PageUp);
+    assert_eq!(list.selected(), [6]);
+    press(&mut list, Key::Home);
+    assert_eq!(rows(&list), ["item0", "item1", "item2"]);
+}
+
+#[test]
+fn clicks_account_for_scrolling() {
+    let mut list = numbers(10);
+    press(&mut list, Key::End);
+
+    let click = |kind| Event::from(MouseEvent::new(kind, 1, 0));
+    assert_eq!(
+        list.handle(&click(MouseKind::Down(MouseButton::Left)), AREA),
+        Some(Message::Highlight(vec![7]))
+    );
+    assert_eq!(
+        list.handle(&click(MouseKind::Up(MouseButton::Left)), AREA),
+        Some(Message::Activate(vec![7]))
+    );
+}
+
+#[test]
+fn empty_lists_ignore_input() {
+    let mut list = numbers(0);
+    assert!(!list.focusable());
+
+    for key in [Key::Down, Key::Up, Key::End, Key::PageDown, Key::Enter] {
+        assert_eq!(press(&mut list, key), None);
+    }
+    let click = Event::from(MouseEvent::new(MouseKind::Down(MouseButton::Left), 1, 1));
+    assert_eq!(list.handle(&click, AREA), None);
+    assert_eq!(rows(&list), ["", "", ""]);
+}
+
+#[test]
+fn disabled_items_are_skipped() {
+    let mut list = List::new([
+        Item::new("a").disabled(true),
+        Item::new("b"),
+        Item::new("c").disabled(true),
+        Item::new("d"),
+    ]);
+    list.set_focused(true);
+    assert_eq!(list.selected(), [1]);
+
+    assert_eq!(
+        press(&mut list, Key::Down),
+        Some(Message::Highlight(vec![3]))
+    );
+    assert_eq!(press(&mut list, Key::Down), None);
+    assert_eq!(
+        press(&mut list, Key::Home),
+        Some(Message::Highlight(vec![1]))
+    );
+}
+
+#[test]
+fn multi_select_checks_items() {
+    let mut list = numbers(3).multi_select(true);
+    assert_eq!(
+        press(&mut list, Key::Char(' ')),
+        Some(Message::Check(vec![0], true))
+    );
+    press(&mut list, Key::Down);
+    press(&mut list, Key::Char(' '));
+    assert_eq!(list.checked(), [vec![0], vec![1]]);
+    press(&mut list, Key::Up);
+    assert_eq!(
+        press(&mut list, Key::Char(' ')),
+        Some(Message::Check(vec![0], false))
+    );
+}
+
+#[test]
+fn renders_into_areas_too_short_for_items() {
+    let mut list = List::new(vec![
+        Item::submenu("Options", vec!["Audio".into(), "Video".into()]),
+        Item::new("Quit"),
+    ]);
+    list.set_focused(true);
+
+    let mut buffer = PixelBuffer::new(6, 1);
+    list.render(&mut buffer, Rect::from([0, 0, 6, 0]));
+
+    list.handle(&Key::Enter.into(), AREA);
+    list.render(&mut buffer, Rect::from([0, 0, 6, 0]));
+    list.render(&mut buffer, Rect::from([0, 0, 6, 1]));
+    let row: String = buffer.pixels.iter().map(|p| p.value).collect();
+    assert_eq!(row, "‹ Opt…");
+}
+
+#[test]
+fn replacing_items_keeps_the_highlight_in_range() {
+    let mut list = List::new(vec![
+        Item::new("Continue"),
+        Item::submenu("Options", vec!["Audio".into(), "Video".into()]),
+        Item::new("Quit"),
+    ])
+    .multi_select(true);
+    list.set_focused(true);
+    press(&mut list, Key::Down);
+    press(&mut list, Key::Enter);
+    press(&mut list, Key::Down);
+    press(&mut list, Key::Char(' '));
+    assert_eq!(list.selected(), [1, 1]);
+
+    list.set_items(vec![
+        Item::new("Continue"),
+        Item::submenu("Options", vec!["Audio".into()]),
+    ]);
+    assert_eq!(list.selected(), [1, 0]);
+    assert!(list.checked().is_empty());
+    assert_eq!(list.current().len(), 1);
+
+    list.set_items(vec!["Continue"]);
+    assert_eq!(list.selected(), [0]);
+    assert_eq!(list.items(), [Item::new("Continue")]);
+    assert_eq!(rows(&list)[1..], ["", ""]);
+
+    list.set_items(Vec::<Item>::new());
+    assert_eq!(list.selected(), [0]);
+    assert!(list.current().is_empty());
+    assert_eq!(press(&mut list, Key::Enter), None);
+}
@@ -0,0 +1,148 @@
+use clige::core::{
+    buffer::{Buffer, PixelBuffer},
+    color::Color,
+    data::{Pixel, Rect},
+    event::{Event, Key, MouseButton, MouseEvent, MouseKind},
+};
+use clige::ui::{
+    panel::{Dialog, Message},
+    Align, Interactive, Panel, Text, Widget,
+};
+
+fn rows(buffer: &PixelBuffer) -> Vec<String> {
+    buffer
+        .pixels
+        .chunks(buffer.width())
+        .map(|row| row.iter().map(|p| p.value).collect())
+        .collect()
+}
+
+fn mouse(kind: MouseKind, x: usize, y: usize) -> Event {
+    MouseEvent::new(kind, x, y).into()
+}
+
+#[test]
+fn children_are_cut_off_at_the_border() {
+    let mut buffer = PixelBuffer::new(8, 3);
+    Panel::new()
+        .child(
+            Rect::from([0, 0, 10, 1]),
+            Text::new("abcd").align(Align::Center),
+        )
+        .render(&mut buffer, Rect::from([0, 0, 8, 3]));
+
+    // Centered in its full width of 10, so only the start fits inside the border
+    assert_eq!(rows(&buffer), ["┌──────┐", "│   abc│", "└──────┘"]);
+}
+
+#[test]
+fn children_keep_what_is_beneath_them() {
+    let mut buffer = PixelBuffer::new(6, 3);
+    fill(&mut buffer, '.');
+    Panel::new()
+        .child(Rect::from([1, 0, 3, 1]), Text::new("ab"))
+        .render(&mut buffer, Rect::from([0, 0, 6, 3]));
+
+    assert_eq!(rows(&buffer), ["┌────┐", "│.ab.│", "└────┘"]);
+}
+
+#[test]
+fn title_and_footer_sit_on_the_edges() {
+    let mut buffer = PixelBuffer::new(10, 3);
+    Panel::new()
+        .title("Hi")
+        .footer("End")
+        .render(&mut buffer, Rect::from([0, 0, 10, 3]));
+    assert_eq!(rows(&buffer), ["┌ Hi ────┐", "│        │", "└ End ───┘"]);
+
+    let mut buffer = PixelBuffer::new(10, 3);
+    Panel::new()
+        .title("Hi")
+        .footer("End")
+        .title_align(Align::Right)
+        .render(&mut buffer, Rect::from([0, 0, 10, 3]));
+    assert_eq!(rows(&buffer), ["┌──── Hi ┐", "│        │", "└─── End ┘"]);
+}
+
+fn fill(buffer: &mut PixelBuffer, value: char) {
+    for pixel in buffer.pixels.iter_mut() {
+        *pixel = Pixel::from(value);
+    }
+}
+
+const AREA: Rect = Rect {
+    left: 0,
+    top: 0,
+    right: 40,
+    bottom: 12,
+};
+
+fn quit() -> Dialog {
+    Dialog::new("Quit", "Leave the game?", ["Yes", "No"])
+}
+
+#[test]
+fn dialog_keys_move_between_buttons() {
+    let mut dialog = quit();
+    assert_eq!(dialog.handle(&Key::Tab.into(), AREA), None);
+    assert_eq!(dialog.handle(&Key::Tab.into(), AREA), None);
+    assert_eq!(dialog.handle(&Key::Left.into(), AREA), None);
+    assert_eq!(
+        dialog.handle(&Key::Char(' ').into(), AREA),
+        Some(Message::Choose(1))
+    );
+    assert!(!dialog.is_open());
+    assert_eq!(dialog.handle(&Key::Enter.into(), AREA), None);
+
+    dialog.open();
+    assert_eq!(
+        dialog.handle(&Key::Enter.into(), AREA),
+        Some(Message::Choose(0))
+    );
+
+    dialog.open();
+    assert_eq!(
+        dialog.handle(&Key::Esc.into(), AREA),
+        Some(Message::Dismiss)
+    );
+    assert!(!dialog.is_open());
+}
+
+#[test]
+fn dialog_buttons_can_be_clicked() {
+    let mut dialog = quit();
+    let mut buffer = PixelBuffer::new(40, 12);
+    dialog.render(&mut buffer, AREA);
+    let rows = rows(&buffer);
+    assert_eq!(rows[3].trim(), "╭ Quit ───────────╮");
+    assert_eq!(rows[6].trim(), "│    Yes   No     │");
+
+    let left = MouseKind::Down(MouseButton::Left);
+    let x = rows[6].chars().position(|c| c == 'N').unwrap();
+    assert_eq!(dialog.handle(&mouse(left, x, 6), AREA), None);
+    assert_eq!(
+        dialog.handle(&mouse(MouseKind::Up(MouseButton::Left), x, 6), AREA),
+        Some(Message::Choose(1))
+    );
+}
+
+#[test]
+fn dialog_dims_what_is_beneath_it() {
+    let dim = Color::new("90", "40");
+    let mut buffer = PixelBuffer::new(40, 12);
+    fill(&mut buffer, '.');
+    quit().render(&mut buffer, AREA);
+    assert_eq!(buffer.pixels[0], Pixel::new('.', dim.clone()));
+    assert_ne!(buffer.get(20, 5).unwrap().color, dim);
+
+    let mut buffer = PixelBuffer::new(40, 12);
+    fill(&mut buffer, '.');
+    quit().dim(None).render(&mut buffer, AREA);
+    assert_eq!(buffer.pixels[0], Pixel::from('.'));
+
+    let mut dialog = quit();
+    dialog.close();
+    let mut buffer = PixelBuffer::new(40, 12);
+    dialog.render(&mut buffer, AREA);
+    assert!(rows(&buffer).iter().all(|row| row.trim().is_empty()));
+}
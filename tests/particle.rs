@@ -0,0 +1,100 @@
+use clige::core::buffer::{Buffer, PixelBuffer};
+use clige::fx::{Emitter, Particle};
+
+fn steady() -> Emitter {
+    Emitter::new(2., 1.)
+        .seed(3)
+        .lifetime(1., 1.)
+        .speed(2., 2.)
+        .direction(0., 0.)
+}
+
+#[test]
+fn particles_expire_at_the_end_of_their_lifetime() {
+    let mut emitter = steady();
+    emitter.burst(3);
+    assert_eq!(emitter.len(), 3);
+
+    emitter.update(0.5);
+    assert_eq!(emitter.len(), 3);
+    let particle = emitter.particles()[0];
+    assert_eq!(particle.life(), 0.5);
+    assert_eq!((particle.x, particle.y), (3., 1.));
+
+    emitter.update(0.5);
+    assert!(emitter.is_empty());
+}
+
+#[test]
+fn lifetimes_fall_within_their_range() {
+    let mut emitter = Emitter::new(0., 0.).seed(9).lifetime(0.5, 2.);
+    emitter.burst(50);
+    assert!(emitter
+        .particles()
+        .iter()
+        .all(|p| (0.5..2.).contains(&p.lifetime)));
+
+    emitter.update(1.);
+    let alive = emitter.len();
+    assert!(alive > 0 && alive < 50);
+    assert!(emitter.particles().iter().all(|p| p.lifetime > 1.));
+    emitter.update(1.);
+    assert!(emitter.is_empty());
+}
+
+#[test]
+fn particles_without_a_lifetime_are_dead() {
+    let particle = Particle {
+        x: 0.,
+        y: 0.,
+        velocity: (0., 0.),
+        age: 0.,
+        lifetime: 0.,
+    };
+    assert_eq!(particle.life(), 1.);
+    assert!(!particle.is_alive());
+
+    let mut emitter = Emitter::new(0., 0.).lifetime(0., 0.);
+    emitter.burst(4);
+    emitter.update(0.);
+    assert!(emitter.is_empty());
+}
+
+#[test]
+fn rate_spawns_particles_over_time() {
+    let mut emitter = steady().rate(4.).max(6);
+    emitter.update(0.1);
+    assert_eq!(emitter.len(), 0);
+    emitter.update(0.2);
+    assert_eq!(emitter.len(), 1);
+    emitter.update(0.5);
+    assert_eq!(emitter.len(), 3);
+
+    // The oldest particles die as new ones keep the count steady, up to the maximum
+    emitter.update(0.75);
+    assert_eq!(emitter.len(), 5);
+    emitter.update(0.25);
+    assert_eq!(emitter.len(), 4);
+
+    // Stopping leaves the particles already alive to live out their lifetime
+    emitter.set_emitting(false);
+    emitter.update(0.5);
+    assert_eq!(emitter.len(), 4);
+    emitter.update(0.5);
+    assert!(emitter.is_empty());
+}
+
+#[test]
+fn characters_change_over_a_particle_life() {
+    let mut emitter = steady().chars("abc").speed(0., 0.);
+    emitter.burst(1);
+
+    let mut drawn = Vec::new();
+    for _ in 0..3 {
+        let mut buffer = PixelBuffer::new(4, 3);
+        emitter.draw(&mut buffer);
+        drawn.push(buffer.get(2, 1).unwrap().value);
+        emitter.update(0.4);
+    }
+    assert_eq!(drawn, ['a', 'b', 'c']);
+}
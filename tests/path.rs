@@ -0,0 +1,113 @@
+use clige::grid::{
+    path::{Connectivity, Pathfinder},
+    Grid,
+};
+
+fn grid(rows: &[&str]) -> Grid<char> {
+    Grid::from_rows(rows.iter().map(|row| row.chars().collect()).collect()).unwrap()
+}
+
+#[test]
+fn flow_field_points_towards_goal() {
+    let map = grid(&["....", ".##.", "...."]);
+    let paths = Pathfinder::new(&map, |_: &char, to: &char| (*to != '#').then_some(1.))
+        .connectivity(Connectivity::Four);
+    let field = paths.flow_field(&[(3, 2)]);
+
+    assert_eq!(field.direction(3, 2), None);
+    assert_eq!(field.direction(2, 2), Some((1, 0)));
+    assert_eq!(field.direction(3, 0), Some((0, 1)));
+
+    let mut cell = (0, 0);
+    for _ in 0..5 {
+        cell = field.next(cell.0, cell.1).unwrap();
+    }
+    assert_eq!(cell, (3, 2));
+}
+
+#[test]
+fn flow_field_leads_across_free_cells() {
+    // Stepping onto '.' costs nothing, so every cell is zero cost from the goal
+    let map = grid(&["...."]);
+    let paths = Pathfinder::new(&map, |_: &char, _: &char| Some(0.));
+    let field = paths.flow_field(&[(3, 0)]);
+
+    assert_eq!(field.direction(3, 0), None);
+    assert_eq!(field.direction(2, 0), Some((1, 0)));
+
+    let mut cell = (0, 0);
+    for _ in 0..3 {
+        cell = field.next(cell.0, cell.1).unwrap();
+    }
+    assert_eq!(cell, (3, 0));
+}
+
+#[test]
+fn flow_field_stops_where_goal_is_unreachable() {
+    let map = grid(&[".#."]);
+    let paths = Pathfinder::new(&map, |_: &char, to: &char| (*to != '#').then_some(1.));
+    let field = paths.flow_field(&[(0, 0)]);
+
+    assert_eq!(field.direction(2, 0), None);
+    assert_eq!(field.next(2, 0), None);
+}
+
+#[test]
+fn astar_finds_paths_across_open_grids_with_fractional_costs() {
+    let map = Grid::new(2, 9, '.');
+    let paths = Pathfinder::new(&map, |_: &char, _: &char| Some(1.));
+    let path = paths.astar((0, 0), (1, 8)).unwrap();
+    assert_eq!(path.cells.len(), 9);
+
+    let corridor = Grid::new(3, 1, '.');
+    let paths = Pathfinder::new(&corridor, |_: &char, _: &char| Some(0.3)).heuristic(0.1);
+    let path = paths.astar((0, 0), (2, 0)).unwrap();
+    assert_eq!(path.cells, [(0, 0), (1, 0), (2, 0)]);
+
+    // Every route matches the cheapest cost found by a Dijkstra map
+    let map = Grid::new(7, 6, '.');
+    for (cost, heuristic) in [(1., 1.), (0.1, 0.1), (0.3, 0.1)] {
+        let paths = Pathfinder::new(&map, |_: &char, _: &char| Some(cost)).heuristic(heuristic);
+        for start in (0..6).flat_map(|y| (0..7).map(move |x| (x, y))) {
+            let costs = paths.dijkstra(&[start]);
+            for goal in (0..6).flat_map(|y| (0..7).map(move |x| (x, y))) {
+                let path = paths.astar(start, goal).unwrap();
+                let expected = costs.cost(goal.0, goal.1).unwrap();
+                assert!(
+                    (path.cost - expected).abs() < 1e-9,
+                    "{:?} to {:?}",
+                    start,
+                    goal
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn dijkstra_paths_lead_across_free_cells() {
+    let map = grid(&["....", ".##.", "...."]);
+    let paths = Pathfinder::new(&map, |_: &char, to: &char| (*to != '#').then_some(0.))
+        .connectivity(Connectivity::Four);
+    let costs = paths.dijkstra(&[(3, 2)]);
+
+    assert_eq!(costs.cost(0, 0), Some(0.));
+    assert_eq!(costs.downhill(3, 2), None);
+    let path = costs.path((0, 0)).unwrap();
+    assert_eq!(path.first(), Some(&(0, 0)));
+    assert_eq!(path.last(), Some(&(3, 2)));
+    assert_eq!(path.len(), 6);
+
+    let paths = Pathfinder::new(&map, |_: &char, to: &char| (*to != '#').then_some(1.))
+        .connectivity(Connectivity::Four);
+    let costs = paths.dijkstra(&[(3, 2)]);
+    assert_eq!(costs.cost(0, 0), Some(5.));
+    assert_eq!(costs.path((0, 0)).unwrap().len(), 6);
+}
+
+#[test]
+#[should_panic(expected = "Step costs can't be negative")]
+fn negative_costs_are_rejected() {
+    let map = grid(&["..."]);
+    Pathfinder::new(&map, |_: &char, _: &char| Some(-1.)).dijkstra(&[(0, 0)]);
+}
@@ -0,0 +1,148 @@
+use std::{fs, path::PathBuf};
+
+use clige::core::{
+    buffer::{Buffer, PixelBuffer},
+    color::Color,
+    data::{Pixel, Rect},
+};
+use clige::save::Saves;
+use serde::{Deserialize, Serialize};
+
+/// An empty directory for one test, removed again when it is dropped
+struct Directory(PathBuf);
+
+impl Directory {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("clige-save-{}-{}", name, std::process::id()));
+        fs::remove_dir_all(&path).ok();
+        Directory(path)
+    }
+}
+
+impl Drop for Directory {
+    fn drop(&mut self) {
+        fs::remove_dir_all(&self.0).ok();
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct State {
+    level: u32,
+    area: Rect,
+    map: PixelBuffer,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Player {
+    name: String,
+    health: u32,
+    gold: u32,
+}
+
+#[test]
+fn saves_round_trip() {
+    let directory = Directory::new("round-trip");
+    let saves = Saves::new(&directory.0, 1);
+    assert_eq!(saves.slots().unwrap(), Vec::<String>::new());
+
+    let mut map = PixelBuffer::new(3, 2);
+    map.set(1, 1, Pixel::new('@', Color::new("31", "44")))
+        .unwrap();
+    let state = State {
+        level: 4,
+        area: Rect::from([1, 2, 3, 4]),
+        map,
+    };
+
+    saves.save("slot-1", &state).unwrap();
+    saves.save("auto_save", &state).unwrap();
+    let loaded: State = saves.load("slot-1").unwrap();
+    assert_eq!(loaded.level, 4);
+    assert_eq!(loaded.area, state.area);
+    assert_eq!(loaded.map.render(), state.map.render());
+    assert_eq!(saves.info("slot-1").unwrap().version, 1);
+
+    assert_eq!(saves.slots().unwrap(), ["auto_save", "slot-1"]);
+    assert!(saves.exists("slot-1"));
+    saves.delete("slot-1").unwrap();
+    assert!(!saves.exists("slot-1"));
+    assert!(saves.load::<State>("slot-1").is_err());
+    assert_eq!(saves.slots().unwrap(), ["auto_save"]);
+}
+
+#[test]
+fn old_saves_are_migrated() {
+    let directory = Directory::new("migrate");
+    Saves::new(&directory.0, 1)
+        .save("hero", &serde_json::json!({ "name": "Ana", "hp": 30 }))
+        .unwrap();
+
+    let saves = Saves::new(&directory.0, 3)
+        .migration(1, |mut data| {
+            // Version 2 renamed hp to health
+            data["health"] = data["hp"].take();
+            Ok(data)
+        })
+        .migration(2, |mut data| {
+            // Version 3 added gold
+            data["gold"] = 10.into();
+            Ok(data)
+        });
+    assert_eq!(
+        saves.load::<Player>("hero").unwrap(),
+        Player {
+            name: "Ana".into(),
+            health: 30,
+            gold: 10
+        }
+    );
+
+    // Missing steps and saves from newer versions can't be loaded
+    assert!(Saves::new(&directory.0, 2).load::<Player>("hero").is_err());
+    saves
+        .save("hero", &saves.load::<Player>("hero").unwrap())
+        .unwrap();
+    assert!(Saves::new(&directory.0, 2).load::<Player>("hero").is_err());
+
+    let failing = Saves::new(&directory.0, 4).migration(3, |_| Err("broken".into()));
+    assert_eq!(failing.load::<Player>("hero"), Err("broken".to_string()));
+}
+
+#[test]
+fn invalid_slot_names_are_rejected() {
+    let directory = Directory::new("names");
+    let saves = Saves::new(&directory.0, 1);
+    for slot in [
+        "",
+        "../escape",
+        "a/b",
+        "a\\b",
+        "with space",
+        "dot.json",
+        "café",
+    ] {
+        assert!(saves.path(slot).is_err(), "{:?}", slot);
+        assert!(saves.save(slot, &1).is_err(), "{:?}", slot);
+        assert!(saves.load::<u32>(slot).is_err());
+        assert!(!saves.exists(slot));
+    }
+    assert!(!directory.0.exists());
+
+    // Files that aren't valid slots are left out of the list
+    saves.save("good", &1).unwrap();
+    fs::write(directory.0.join("not a slot.json"), "{}").unwrap();
+    fs::write(directory.0.join("notes.txt"), "").unwrap();
+    assert_eq!(saves.slots().unwrap(), ["good"]);
+}
+
+#[test]
+fn corrupt_saves_fail_to_load() {
+    let directory = Directory::new("corrupt");
+    let saves = Saves::new(&directory.0, 1);
+    saves.save("slot", &1).unwrap();
+    fs::write(saves.path("slot").unwrap(), "{ not json").unwrap();
+
+    let error = saves.load::<u32>("slot").unwrap_err();
+    assert!(error.contains("corrupt"), "{}", error);
+    assert!(saves.info("slot").is_err());
+}
@@ -0,0 +1,174 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use clige::core::{
+    buffer::{Buffer, PixelBuffer},
+    data::Pixel,
+    event::{Event, Key},
+};
+use clige::scene::{Command, Edge, Scene, SceneStack, Transition};
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+/// Fills the screen with its name's first letter and logs every call it gets
+struct Logged {
+    name: &'static str,
+    log: Log,
+    transparent: bool,
+}
+
+impl Logged {
+    fn new(name: &'static str, log: &Log) -> Self {
+        Logged {
+            name,
+            log: log.clone(),
+            transparent: false,
+        }
+    }
+
+    fn record(&self, call: &str) {
+        self.log
+            .borrow_mut()
+            .push(format!("{} {}", self.name, call));
+    }
+}
+
+impl Scene for Logged {
+    fn enter(&mut self) {
+        self.record("enter");
+    }
+
+    fn exit(&mut self) {
+        self.record("exit");
+    }
+
+    fn pause(&mut self) {
+        self.record("pause");
+    }
+
+    fn resume(&mut self) {
+        self.record("resume");
+    }
+
+    fn handle(&mut self, event: &Event) -> Command {
+        self.record("handle");
+        match event {
+            Event::Key(key) if key.key == Key::Esc => Command::Pop(Transition::Cut),
+            Event::Key(key) if key.key == Key::Char('q') => Command::Quit,
+            _ => Command::None,
+        }
+    }
+
+    fn update(&mut self, _delta: f64) -> Command {
+        self.record("update");
+        Command::None
+    }
+
+    fn render(&mut self, buffer: &mut PixelBuffer) {
+        let value = self.name.chars().next().unwrap();
+        let start = usize::from(self.transparent);
+        for x in start..buffer.width() {
+            buffer.set(x, 0, Pixel::from(value)).unwrap();
+        }
+    }
+
+    fn transparent(&self) -> bool {
+        self.transparent
+    }
+}
+
+fn screen(scenes: &mut SceneStack) -> String {
+    let mut buffer = PixelBuffer::new(4, 1);
+    scenes.render(&mut buffer);
+    buffer.pixels.iter().map(|p| p.value).collect()
+}
+
+fn take(log: &Log) -> Vec<String> {
+    log.borrow_mut().drain(..).collect()
+}
+
+#[test]
+fn push_pop_and_replace_call_the_lifecycle_hooks() {
+    let log = Log::default();
+    let mut scenes = SceneStack::new(Logged::new("game", &log));
+    assert_eq!(take(&log), ["game enter"]);
+
+    scenes.push(Logged::new("menu", &log), Transition::Cut);
+    assert_eq!(take(&log), ["game pause", "menu enter"]);
+    assert_eq!(scenes.len(), 2);
+    assert_eq!(screen(&mut scenes), "mmmm");
+
+    scenes.update(0.1);
+    assert_eq!(take(&log), ["menu update"]);
+
+    scenes.replace(Logged::new("options", &log), Transition::Cut);
+    assert_eq!(take(&log), ["menu exit", "options enter"]);
+    assert_eq!(scenes.len(), 2);
+
+    scenes.handle(&Key::Esc.into());
+    assert_eq!(
+        take(&log),
+        ["options handle", "options exit", "game resume"]
+    );
+    assert_eq!(screen(&mut scenes), "gggg");
+
+    scenes.handle(&Key::Char('q').into());
+    assert_eq!(take(&log), ["game handle", "game exit"]);
+    assert!(!scenes.is_running());
+    assert_eq!(screen(&mut scenes), "    ");
+}
+
+#[test]
+fn transparent_scenes_show_the_scene_below() {
+    let log = Log::default();
+    let mut scenes = SceneStack::new(Logged::new("game", &log));
+    let mut pause = Logged::new("pause", &log);
+    pause.transparent = true;
+    scenes.push(pause, Transition::Cut);
+    assert_eq!(screen(&mut scenes), "gppp");
+}
+
+#[test]
+fn transitions_block_updates_until_they_complete() {
+    let log = Log::default();
+    let mut scenes = SceneStack::new(Logged::new("game", &log));
+    scenes.push(Logged::new("menu", &log), Transition::wipe(Edge::Left, 1.));
+    take(&log);
+    assert!(scenes.transitioning());
+
+    scenes.update(0.5);
+    scenes.handle(&Key::Esc.into());
+    assert!(take(&log).is_empty());
+    assert_eq!(screen(&mut scenes), "mmgg");
+
+    scenes.update(0.5);
+    assert!(!scenes.transitioning());
+    assert_eq!(screen(&mut scenes), "mmmm");
+    scenes.update(0.1);
+    assert_eq!(take(&log), ["menu update"]);
+}
+
+#[test]
+fn popped_scenes_are_drawn_until_the_transition_ends() {
+    let log = Log::default();
+    let mut scenes = SceneStack::new(Logged::new("game", &log));
+    scenes.push(Logged::new("menu", &log), Transition::Cut);
+    scenes.pop(Transition::wipe(Edge::Right, 2.));
+    assert_eq!(scenes.len(), 1);
+
+    scenes.update(1.);
+    assert_eq!(screen(&mut scenes), "mmgg");
+    scenes.update(1.);
+    assert_eq!(screen(&mut scenes), "gggg");
+}
+
+#[test]
+fn a_new_command_finishes_the_playing_transition() {
+    let log = Log::default();
+    let mut scenes = SceneStack::new(Logged::new("game", &log));
+    scenes.push(Logged::new("menu", &log), Transition::fade(1.));
+    scenes.replace(Logged::new("options", &log), Transition::Cut);
+    assert!(!scenes.transitioning());
+    assert_eq!(screen(&mut scenes), "oooo");
+    assert_eq!(Transition::fade(-1.).duration(), 0.);
+}
@@ -0,0 +1,80 @@
+//! Pins seeds to the numbers they produce. Shared world seeds must generate the same
+//! worlds after a toolchain or dependency upgrade, so none of these values may change.
+
+use clige::gen::Random;
+use rand::Rng;
+
+fn first(mut random: Random) -> [u64; 3] {
+    [
+        random.generator().gen(),
+        random.generator().gen(),
+        random.generator().gen(),
+    ]
+}
+
+#[test]
+fn string_seeds_use_fnv1a() {
+    // Reference values for 64 bit FNV-1a
+    assert_eq!(Random::from("").seed() as u64, 0xcbf2_9ce4_8422_2325);
+    assert_eq!(Random::from("a").seed() as u64, 0xaf63_dc4c_8601_ec8c);
+
+    assert_eq!(
+        Random::from("Some Seed goes here 2109@").seed(),
+        -2180681063658574884
+    );
+    assert_eq!(Random::from("🌍 world").seed(), -7450851872449912809);
+}
+
+#[test]
+fn numeric_strings_parse_as_seeds() {
+    assert_eq!(
+        Random::from("6100374812663787999").seed(),
+        6100374812663787999
+    );
+    assert_eq!(Random::from("-42").seed(), -42);
+    assert_eq!(
+        first(Random::from("6100374812663787999")),
+        first(Random::from(6100374812663787999))
+    );
+}
+
+#[test]
+fn seeds_produce_known_numbers() {
+    assert_eq!(
+        first(Random::from(6100374812663787999)),
+        [
+            11253173331112204588,
+            6498273116508955940,
+            9799763426654536515
+        ]
+    );
+    assert_eq!(
+        first(Random::from(-42)),
+        [620576636562557833, 456610458071828645, 4820781131103170978]
+    );
+    assert_eq!(
+        first(Random::from("Some Seed goes here 2109@")),
+        [
+            3994195777322020915,
+            1009458811006809765,
+            10904745264857438138
+        ]
+    );
+    assert_eq!(
+        first(Random::from("")),
+        [
+            912914490797288947,
+            1529299921456782224,
+            16769170429574109693
+        ]
+    );
+}
+
+#[test]
+fn forks_produce_known_numbers() {
+    let world = Random::from("Some Seed goes here 2109@");
+
+    assert_eq!(first(world.fork("terrain"))[0], 1376698330942068635);
+    assert_eq!(first(world.fork_at(-3, 8))[0], 11300768870772851022);
+    assert_eq!(world.noise_seed(), 1431667813);
+}
@@ -0,0 +1,73 @@
+use clige::core::{
+    data::Rect,
+    event::{Event, Key, MouseButton, MouseEvent, MouseKind},
+};
+use clige::ui::{slider::Changed, Interactive, Slider};
+
+const AREA: Rect = Rect {
+    left: 0,
+    top: 0,
+    right: 11,
+    bottom: 1,
+};
+
+fn key(key: Key) -> Event {
+    key.into()
+}
+
+#[test]
+fn keys_move_small_integer_sliders() {
+    let mut slider = Slider::<i32>::new(0, 10);
+    slider.set_focused(true);
+
+    assert_eq!(slider.handle(&key(Key::Right), AREA), Some(Changed(1)));
+    assert_eq!(slider.handle(&key(Key::Up), AREA), Some(Changed(2)));
+    assert_eq!(slider.handle(&key(Key::Left), AREA), Some(Changed(1)));
+    assert_eq!(slider.handle(&key(Key::PageUp), AREA), Some(Changed(10)));
+    assert_eq!(slider.handle(&key(Key::Right), AREA), None);
+    assert_eq!(slider.handle(&key(Key::Home), AREA), Some(Changed(0)));
+    assert_eq!(slider.handle(&key(Key::Down), AREA), None);
+}
+
+#[test]
+fn keys_move_by_a_hundredth_of_large_ranges() {
+    let mut slider = Slider::<u32>::new(0, 1000);
+    slider.set_focused(true);
+    assert_eq!(slider.handle(&key(Key::Right), AREA), Some(Changed(10)));
+
+    let mut slider = Slider::new(0.0, 1.0);
+    slider.set_focused(true);
+    assert_eq!(slider.handle(&key(Key::Right), AREA), Some(Changed(0.01)));
+}
+
+#[test]
+fn keys_use_the_step() {
+    let mut slider = Slider::<i32>::new(0, 10).step(3);
+    slider.set_focused(true);
+
+    assert_eq!(slider.handle(&key(Key::Right), AREA), Some(Changed(3)));
+    // The last step would leave the range, so the value stays on a multiple of it
+    assert_eq!(slider.handle(&key(Key::End), AREA), Some(Changed(9)));
+}
+
+#[test]
+fn keys_need_focus() {
+    let mut slider = Slider::<i32>::new(0, 10);
+    assert_eq!(slider.handle(&key(Key::Right), AREA), None);
+    assert_eq!(slider.get(), 0);
+}
+
+#[test]
+fn dragging_sets_the_value() {
+    let mouse = |kind, x| Event::from(MouseEvent::new(kind, x, 0));
+    let mut slider = Slider::<i32>::new(0, 10);
+
+    let down = mouse(MouseKind::Down(MouseButton::Left), 5);
+    assert_eq!(slider.handle(&down, AREA), Some(Changed(5)));
+    let drag = mouse(MouseKind::Drag(MouseButton::Left), 20);
+    assert_eq!(slider.handle(&drag, AREA), Some(Changed(10)));
+
+    slider.handle(&mouse(MouseKind::Up(MouseButton::Left), 20), AREA);
+    let drag = mouse(MouseKind::Drag(MouseButton::Left), 0);
+    assert_eq!(slider.handle(&drag, AREA), None);
+}
@@ -0,0 +1,131 @@
+use std::collections::BTreeSet;
+
+use clige::core::data::Rect;
+use clige::gen::Random;
+use clige::physics::spatial::{Quadtree, SpatialHash, SpatialIndex};
+use rand::Rng;
+
+/// Squared distance from a cell to the nearest cell of a rect
+fn distance(rect: &Rect, x: usize, y: usize) -> usize {
+    let dx = x.saturating_sub(rect.right - 1) + rect.left.saturating_sub(x);
+    let dy = y.saturating_sub(rect.bottom - 1) + rect.top.saturating_sub(y);
+    dx * dx + dy * dy
+}
+
+/// Rects sitting on the edges of 8 wide buckets and the quadrants of a 64 wide tree,
+/// followed by random ones
+fn rects(seed: i64) -> Vec<Rect> {
+    let mut rects = vec![
+        Rect::from([7, 7, 9, 9]),
+        Rect::from([8, 0, 9, 1]),
+        Rect::from([31, 31, 33, 33]),
+        Rect::from([32, 0, 33, 64]),
+        Rect::from([0, 32, 64, 33]),
+        Rect::from([16, 16, 24, 24]),
+        Rect::from([63, 63, 64, 64]),
+        Rect::from([70, 40, 75, 41]),
+    ];
+
+    let mut random = Random::from(seed);
+    let rng = random.generator();
+    for _ in 0..60 {
+        let (x, y) = (rng.gen_range(0..72), rng.gen_range(0..72));
+        let (w, h) = (rng.gen_range(1..12), rng.gen_range(1..12));
+        rects.push(Rect::from([x, y, x + w, y + h]));
+    }
+    rects
+}
+
+fn indexes(rects: &[Rect]) -> Vec<Box<dyn SpatialIndex<usize>>> {
+    let mut hash = SpatialHash::new(8);
+    let mut tree = Quadtree::new(Rect::from([0, 0, 64, 64])).capacity(2);
+    for (key, rect) in rects.iter().enumerate() {
+        hash.insert(key, *rect);
+        tree.insert(key, *rect);
+    }
+    vec![Box::new(hash), Box::new(tree)]
+}
+
+#[test]
+fn nearest_matches_a_brute_force_search() {
+    for seed in 0..10 {
+        let rects = rects(seed);
+        for index in indexes(&rects) {
+            for y in (0..80).step_by(4).chain([7, 8, 31, 32, 33, 63, 64]) {
+                for x in (0..80).step_by(4).chain([7, 8, 31, 32, 33, 63, 64]) {
+                    let closest = rects.iter().map(|r| distance(r, x, y)).min();
+                    let found = index.nearest(x, y).map(|key| distance(&rects[key], x, y));
+                    assert_eq!(found, closest, "nearest to ({}, {})", x, y);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn pairs_match_a_brute_force_search() {
+    for seed in 0..10 {
+        let rects = rects(seed);
+        let mut expected = BTreeSet::new();
+        for (a, first) in rects.iter().enumerate() {
+            for (b, second) in rects.iter().enumerate().skip(a + 1) {
+                if first.overlaps(second) {
+                    expected.insert((a, b));
+                }
+            }
+        }
+
+        for index in indexes(&rects) {
+            let pairs = index.pairs();
+            let found = pairs
+                .iter()
+                .map(|(a, b)| (*a.min(b), *a.max(b)))
+                .collect::<BTreeSet<_>>();
+            assert_eq!(pairs.len(), found.len(), "pairs are given once");
+            assert_eq!(found, expected);
+        }
+    }
+}
+
+#[test]
+fn queries_match_a_brute_force_search() {
+    let rects = rects(99);
+    let areas = [
+        Rect::from([0, 0, 8, 8]),
+        Rect::from([8, 8, 16, 16]),
+        Rect::from([30, 30, 34, 34]),
+        Rect::from([32, 32, 33, 33]),
+        Rect::from([60, 0, 80, 80]),
+    ];
+    for index in indexes(&rects) {
+        for area in areas.iter() {
+            let expected = (0..rects.len())
+                .filter(|key| rects[*key].overlaps(area))
+                .collect::<BTreeSet<_>>();
+            let found = index.query(area).into_iter().collect::<BTreeSet<_>>();
+            assert_eq!(found, expected, "query {:?}", area);
+        }
+    }
+}
+
+#[test]
+fn removed_and_moved_rects_are_found_in_their_new_place() {
+    let mut hash = SpatialHash::new(8);
+    let mut tree = Quadtree::new(Rect::from([0, 0, 64, 64])).capacity(1);
+    let indexes: [&mut dyn SpatialIndex<&str>; 2] = [&mut hash, &mut tree];
+    for index in indexes {
+        index.insert("a", Rect::from([7, 7, 9, 9]));
+        index.insert("b", Rect::from([32, 32, 34, 34]));
+        assert_eq!(index.nearest(40, 40), Some("b"));
+
+        assert!(index.relocate("b", Rect::from([0, 60, 2, 62])));
+        assert_eq!(index.point(32, 32), Vec::<&str>::new());
+        assert_eq!(index.nearest(20, 20), Some("a"));
+        assert_eq!(index.pairs(), Vec::new());
+
+        assert_eq!(index.remove("a"), Some(Rect::from([7, 7, 9, 9])));
+        assert!(!index.relocate("a", Rect::from([0, 0, 1, 1])));
+        assert_eq!(index.nearest(8, 8), Some("b"));
+        assert_eq!(index.len(), 1);
+    }
+}
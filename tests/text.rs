@@ -0,0 +1,81 @@
+use clige::core::{
+    buffer::{Buffer, PixelBuffer},
+    data::{Pixel, Rect},
+};
+use clige::ui::{
+    text::{Overflow, Text, Wrap},
+    Align, VAlign, Widget,
+};
+
+fn render(text: &Text, width: usize, height: usize) -> Vec<String> {
+    let mut buffer = PixelBuffer::new(width, height);
+    text.render(&mut buffer, Rect::from([0, 0, width, height]));
+    (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| buffer.get(x, y).unwrap().value)
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn word_wrap_breaks_between_words() {
+    let text = Text::new("the quick brown fox");
+    assert_eq!(text.lines(10), ["the quick", "brown fox"]);
+    // Words wider than the line are split
+    assert_eq!(Text::new("abcdefgh ij").lines(4), ["abcd", "efgh", "ij"]);
+    // Hard line breaks are kept
+    assert_eq!(Text::new("a\nb").lines(10), ["a", "b"]);
+}
+
+#[test]
+fn char_wrap_breaks_anywhere() {
+    let text = Text::new("the quick").wrap(Wrap::Char);
+    assert_eq!(text.lines(4), ["the ", "quic", "k"]);
+    assert_eq!(
+        Text::new("the quick").wrap(Wrap::None).lines(4),
+        ["the quick"]
+    );
+}
+
+#[test]
+fn alignment() {
+    let text = |align| Text::new("ab cd").align(align);
+    assert_eq!(render(&text(Align::Left), 9, 1), ["ab cd    "]);
+    assert_eq!(render(&text(Align::Center), 9, 1), ["  ab cd  "]);
+    assert_eq!(render(&text(Align::Right), 9, 1), ["    ab cd"]);
+
+    // Every line but the last of a paragraph is stretched to fill the area
+    let justified = Text::new("a b c d").align(Align::Justify);
+    assert_eq!(render(&justified, 6, 2), ["a  b c", "d     "]);
+
+    let bottom = Text::new("ab").valign(VAlign::Bottom);
+    assert_eq!(render(&bottom, 2, 3), ["  ", "  ", "ab"]);
+}
+
+#[test]
+fn ellipsis_marks_cut_off_content() {
+    let text = Text::new("abcdefgh").wrap(Wrap::None);
+    assert_eq!(render(&text, 5, 1), ["abcde"]);
+    assert_eq!(
+        render(&text.clone().overflow(Overflow::Ellipsis), 5, 1),
+        ["abcd…"]
+    );
+
+    // Rows that don't fit end the last visible row with an ellipsis
+    let text = Text::new("one two three").overflow(Overflow::Ellipsis);
+    assert_eq!(render(&text, 5, 2), ["one  ", "two… "]);
+}
+
+#[test]
+fn wide_characters_fill_continuation_cells() {
+    let mut buffer = PixelBuffer::new(5, 1);
+    Text::new("a世b").render(&mut buffer, Rect::from([0, 0, 5, 1]));
+
+    let values: Vec<char> = buffer.pixels.iter().map(|p| p.value).collect();
+    assert_eq!(values, ['a', '世', Pixel::CONTINUATION, 'b', ' ']);
+
+    // Wide characters are never split across lines
+    assert_eq!(Text::new("世界").wrap(Wrap::Char).lines(3), ["世", "界"]);
+}
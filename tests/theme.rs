@@ -0,0 +1,53 @@
+use clige::core::{
+    buffer::{Buffer, PixelBuffer},
+    color::{Color, Context},
+    data::Rect,
+};
+use clige::ui::{theme::Role, Button, Gauge, Panel, State, Theme, Toggle, Widget};
+
+fn first_color<W: Widget>(widget: &W) -> Color {
+    let mut buffer = PixelBuffer::new(10, 3);
+    widget.render(&mut buffer, Rect::from([0, 0, 10, 3]));
+    buffer.pixels[0].color.clone()
+}
+
+#[test]
+fn themes_keep_colors_set_on_the_widget() {
+    let red = Color::xterm(196, Context::Foreground);
+    let theme = Theme::light();
+
+    let button = Button::new("Go")
+        .style(State::Normal, red.clone())
+        .themed(&theme);
+    assert_eq!(first_color(&button), red);
+
+    let toggle = Toggle::checkbox("Sound")
+        .style(State::Normal, red.clone())
+        .themed(&theme);
+    assert_eq!(first_color(&toggle), red);
+
+    let panel = Panel::new().color(red.clone()).themed(&theme);
+    assert_eq!(first_color(&panel), red);
+
+    let gauge = Gauge::new(1.).color(red.clone()).themed(&theme);
+    assert_eq!(first_color(&gauge), red);
+}
+
+#[test]
+fn themes_fill_in_the_other_colors() {
+    let theme = Theme::light();
+    let red = Color::xterm(196, Context::Foreground);
+
+    let button = Button::new("Go").style(State::Focused, red).themed(&theme);
+    assert_eq!(first_color(&button), theme.color("button", Role::Text));
+
+    let panel = Panel::new().themed(&Theme::dark()).themed(&theme);
+    assert_eq!(first_color(&panel), theme.color("panel", Role::Border));
+}
+
+#[test]
+fn malformed_theme_files_are_errors() {
+    assert!(Theme::parse("primary = #aé€").is_err());
+    assert!(Theme::parse("primary = #12345").is_err());
+    assert!(Theme::parse("primary = #005fd7 bold").is_ok());
+}
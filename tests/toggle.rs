@@ -0,0 +1,73 @@
+use clige::core::{
+    data::Rect,
+    event::{Event, Key, MouseButton, MouseEvent, MouseKind},
+};
+use clige::ui::{
+    toggle::{Selected, Toggled},
+    Interactive, RadioGroup, Toggle,
+};
+
+fn down(x: usize, y: usize) -> Event {
+    MouseEvent::new(MouseKind::Down(MouseButton::Left), x, y).into()
+}
+
+fn up(x: usize, y: usize) -> Event {
+    MouseEvent::new(MouseKind::Up(MouseButton::Left), x, y).into()
+}
+
+#[test]
+fn toggles_flip_on_click_and_keys() {
+    let area = Rect::from([0, 0, 10, 1]);
+    let mut toggle = Toggle::checkbox("Sound");
+
+    toggle.handle(&down(1, 0), area);
+    assert_eq!(toggle.handle(&up(2, 0), area), Some(Toggled(true)));
+    toggle.handle(&down(1, 0), area);
+    assert_eq!(toggle.handle(&up(20, 0), area), None);
+
+    assert_eq!(toggle.handle(&Key::Enter.into(), area), None);
+    toggle.set_focused(true);
+    assert_eq!(
+        toggle.handle(&Key::Enter.into(), area),
+        Some(Toggled(false))
+    );
+
+    toggle.set_disabled(true);
+    assert_eq!(toggle.handle(&Key::Enter.into(), area), None);
+    assert!(!toggle.value());
+}
+
+#[test]
+fn radio_selects_the_clicked_row() {
+    let area = Rect::from([0, 5, 10, 8]);
+    let mut radio = RadioGroup::new(["Easy", "Normal", "Hard"]);
+
+    radio.handle(&down(1, 7), area);
+    assert_eq!(radio.handle(&up(1, 7), area), Some(Selected(2)));
+    assert_eq!(radio.selected(), 2);
+}
+
+#[test]
+fn radio_ignores_releases_on_another_row() {
+    let area = Rect::from([0, 5, 10, 8]);
+    let mut radio = RadioGroup::new(["Easy", "Normal", "Hard"]);
+
+    radio.handle(&down(1, 5), area);
+    assert_eq!(radio.handle(&up(1, 6), area), None);
+    // A release without a press on the group doesn't select either
+    assert_eq!(radio.handle(&up(1, 6), area), None);
+    assert_eq!(radio.selected(), 0);
+}
+
+#[test]
+fn radio_keys_move_the_selection() {
+    let area = Rect::from([0, 0, 10, 3]);
+    let mut radio = RadioGroup::new(["Easy", "Normal", "Hard"]);
+    radio.set_focused(true);
+
+    assert_eq!(radio.handle(&Key::Up.into(), area), None);
+    assert_eq!(radio.handle(&Key::Down.into(), area), Some(Selected(1)));
+    assert_eq!(radio.handle(&Key::End.into(), area), Some(Selected(2)));
+    assert_eq!(radio.handle(&Key::Down.into(), area), None);
+    assert_eq!(radio.handle(&Key::Home.into(), area), Some(Selected(0)));
+}
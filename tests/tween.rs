@@ -0,0 +1,34 @@
+use clige::fx::{Animation, Parallel, Repeat, Sequence, Tween};
+
+#[test]
+fn repeat_times_counts_every_play() {
+    let tween = Tween::new(0., 1., 2.).delay(1.).repeat(Repeat::Times(3));
+    assert_eq!(tween.duration(), Some(7.));
+
+    let tween = Tween::new(0., 1., 2.).repeat(Repeat::Forever);
+    assert_eq!(tween.duration(), None);
+}
+
+#[test]
+fn repeat_zero_times_plays_once() {
+    let mut tween = Tween::new(0., 1., 2.).delay(1.).repeat(Repeat::Times(0));
+    assert_eq!(tween.duration(), Some(3.));
+
+    assert_eq!(tween.update(2.), 0.);
+    assert!(!tween.is_finished());
+    assert_eq!(tween.update(2.), 1.);
+    assert!(tween.is_finished());
+    assert_eq!(tween.value(), 1.);
+
+    let sequence = Sequence::new()
+        .then(Tween::new(0., 1., 1.))
+        .wait(2.)
+        .repeat(Repeat::Times(0));
+    assert_eq!(sequence.duration(), Some(3.));
+
+    let parallel = Parallel::new()
+        .with(Tween::new(0., 1., 1.))
+        .with(Tween::new(0., 1., 4.))
+        .repeat(Repeat::Times(0));
+    assert_eq!(parallel.duration(), Some(4.));
+}